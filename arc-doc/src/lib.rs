@@ -12,8 +12,11 @@
 //! - Arc<Atomic*> -> lock-free shared counters/flags
 //! - Arc<Something> + Weak<Something> -> shared graphs/trees without cycles
 
-use std::sync::{Arc, Mutex, RwLock};
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex, RwLock};
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+use std::marker::PhantomData;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
 use std::rc::Rc; // only used in doc contrast
 use std::thread;
 use std::time::Duration;
@@ -164,6 +167,663 @@ pub fn example_weak_to_avoid_cycles() {
 }
 
 
+/// Read-mostly shared config: readers grab a cheap `Arc<T>` snapshot without
+/// locking, a writer publishes a whole new `Arc<T>` with one atomic swap.
+///
+/// `ptr` holds a pointer produced by `Arc::into_raw` (it owns one strong ref).
+/// The tricky part is the window between a reader loading the pointer and
+/// bumping the strong count: if the writer dropped the old Arc in that window
+/// the reader would touch freed memory. Readers announce themselves in one of two
+/// counters, picked by the current `epoch`. A writer swaps the pointer, then flips
+/// the epoch and waits only for the counter of the epoch it just closed: readers
+/// arriving after the flip go to the other counter and see the new pointer, so the
+/// wait is bounded even under a steady stream of `load()`s. Writers take turns
+/// (`writer` lock) so an epoch isn't reopened while its readers are still draining.
+pub struct SharedConfig<T> {
+    ptr: AtomicPtr<T>,
+    epoch: AtomicUsize,
+    readers: [AtomicUsize; 2], // readers inside `load()`, per epoch parity
+    writer: Mutex<()>,
+    _owns: PhantomData<Arc<T>>, // Send/Sync follow Arc<T>, not AtomicPtr
+}
+
+impl<T> SharedConfig<T> {
+    pub fn new(value: T) -> Self {
+        let raw = Arc::into_raw(Arc::new(value)) as *mut T;
+        SharedConfig {
+            ptr: AtomicPtr::new(raw),
+            epoch: AtomicUsize::new(0),
+            readers: [AtomicUsize::new(0), AtomicUsize::new(0)],
+            writer: Mutex::new(()),
+            _owns: PhantomData,
+        }
+    }
+
+    /// Cheap snapshot: one atomic load + one strong-count increment (plus the
+    /// reader-counter bookkeeping).
+    pub fn load(&self) -> Arc<T> {
+        // Register under the current epoch; if a writer flipped it meanwhile, our
+        // counter may already have been waited on, so back out and retry.
+        let slot = loop {
+            let e = self.epoch.load(Ordering::SeqCst) & 1;
+            self.readers[e].fetch_add(1, Ordering::SeqCst);
+            if self.epoch.load(Ordering::SeqCst) & 1 == e {
+                break e;
+            }
+            self.readers[e].fetch_sub(1, Ordering::SeqCst);
+        };
+        let raw = self.ptr.load(Ordering::SeqCst);
+        // SAFETY: `raw` came from Arc::into_raw. If it's already been swapped out,
+        // the writer that did it flips the epoch afterwards and waits for
+        // `readers[slot]` to drain before releasing it.
+        let snapshot = unsafe {
+            Arc::increment_strong_count(raw);
+            Arc::from_raw(raw)
+        };
+        self.readers[slot].fetch_sub(1, Ordering::SeqCst);
+        snapshot
+    }
+
+    /// Publish a new config. Readers holding old snapshots keep them alive.
+    pub fn store(&self, value: T) {
+        let new_raw = Arc::into_raw(Arc::new(value)) as *mut T;
+        let _turn = self.writer.lock().unwrap();
+        let old_raw = self.ptr.swap(new_raw, Ordering::SeqCst);
+        // Close the current epoch. Only readers registered in it can have seen
+        // `old_raw`; everyone who registers from now on reads `new_raw`.
+        let closed = self.epoch.fetch_add(1, Ordering::SeqCst) & 1;
+        while self.readers[closed].load(Ordering::SeqCst) != 0 {
+            thread::yield_now(); // each of them is a handful of instructions from done
+        }
+        // SAFETY: we owned the strong ref stored in `ptr`; no reader can still be
+        // between loading `old_raw` and taking its own strong ref.
+        unsafe { drop(Arc::from_raw(old_raw)) };
+    }
+}
+
+impl<T> Drop for SharedConfig<T> {
+    fn drop(&mut self) {
+        // SAFETY: &mut self -> no concurrent readers; release our strong ref.
+        unsafe { drop(Arc::from_raw(*self.ptr.get_mut())) };
+    }
+}
+
+#[derive(Debug)]
+struct AppConfig {
+    version: u64,
+    name: String,
+    // Invariant checked by readers: always equal to `version * 2`.
+    check: u64,
+}
+
+pub fn example_shared_config_swap() {
+    println!("\n== Example 7: Read-mostly config with an atomic Arc pointer swap ==");
+    let cfg = Arc::new(SharedConfig::new(AppConfig { version: 0, name: "v0".into(), check: 0 }));
+
+    let mut readers = vec![];
+    for i in 0..4 {
+        let c = Arc::clone(&cfg);
+        readers.push(thread::spawn(move || {
+            let mut last_seen = 0;
+            for _ in 0..10_000 {
+                let snap = c.load();
+                // Fully formed: every field belongs to the same version.
+                assert_eq!(snap.check, snap.version * 2);
+                assert_eq!(snap.name, format!("v{}", snap.version));
+                // A single writer only moves forward.
+                assert!(snap.version >= last_seen);
+                last_seen = snap.version;
+            }
+            println!("[reader {i}] last version seen = {last_seen}");
+        }));
+    }
+
+    for v in 1..=100 {
+        cfg.store(AppConfig { version: v, name: format!("v{v}"), check: v * 2 });
+    }
+    for h in readers { h.join().unwrap(); }
+
+    let last = cfg.load();
+    println!("final config = {:?}", last);
+    assert_eq!(last.version, 100);
+}
+
+pub fn example_poison_recovery() {
+    println!("\n== Example 8: Recovering from a poisoned Arc<Mutex<T>> ==");
+    let data: Arc<Mutex<Vec<i32>>> = Arc::new(Mutex::new(vec![1, 2, 3]));
+
+    // A worker panics halfway through an update, while holding the lock.
+    let d = Arc::clone(&data);
+    let result = thread::spawn(move || {
+        let mut guard = d.lock().unwrap();
+        guard.push(4);
+        // Expected: the panic message shows up on stderr when running the demo.
+        panic!("worker died mid-update"); // guard dropped during unwind -> poisoned
+    })
+    .join();
+    assert!(result.is_err()); // the panic surfaced through join()
+    assert!(data.is_poisoned());
+
+    // lock() now returns Err(PoisonError) — but the data is still there.
+    let mut guard = match data.lock() {
+        Ok(g) => g,
+        Err(poisoned) => {
+            println!("lock is poisoned; recovering the guard");
+            poisoned.into_inner() // PoisonError<MutexGuard<..>> -> MutexGuard<..>
+        }
+    };
+    println!("data seen after panic = {:?}", *guard); // [1, 2, 3, 4]: partial update
+
+    // Repair the invariant the worker broke (here: it should have pushed 4 and 5).
+    if guard.last() == Some(&4) {
+        guard.push(5);
+    }
+    drop(guard);
+    data.clear_poison(); // mark as healthy again (Rust 1.77+)
+
+    let repaired = data.lock().unwrap(); // plain unwrap works again
+    println!("repaired data = {:?}", *repaired);
+    assert_eq!(*repaired, [1, 2, 3, 4, 5]);
+}
+
+/// Broadcast channel: every message is cloned into each current subscriber's queue.
+///
+/// One `Arc<(Mutex<..>, Condvar)>` is shared by the sender and all receivers.
+/// Receivers sleep on the Condvar until their own queue has something in it.
+pub struct Broadcast<T: Clone> {
+    shared: Arc<BroadcastShared<T>>,
+}
+
+pub struct Subscriber<T: Clone> {
+    id: u64,
+    shared: Arc<BroadcastShared<T>>,
+}
+
+struct BroadcastShared<T> {
+    state: Mutex<BroadcastState<T>>,
+    ready: Condvar, // signalled on every send and when the sender goes away
+}
+
+struct BroadcastState<T> {
+    queues: Vec<(u64, VecDeque<T>)>, // one slot per live subscriber
+    next_id: u64,
+    closed: bool,
+}
+
+impl<T: Clone> Broadcast<T> {
+    pub fn new() -> Self {
+        let state = BroadcastState { queues: Vec::new(), next_id: 0, closed: false };
+        Broadcast { shared: Arc::new(BroadcastShared { state: Mutex::new(state), ready: Condvar::new() }) }
+    }
+
+    /// New subscribers only see messages sent after they subscribed.
+    pub fn subscribe(&self) -> Subscriber<T> {
+        let mut st = self.shared.state.lock().unwrap();
+        let id = st.next_id;
+        st.next_id += 1;
+        st.queues.push((id, VecDeque::new()));
+        Subscriber { id, shared: Arc::clone(&self.shared) }
+    }
+
+    /// Clone `value` to every current subscriber; returns how many got it.
+    pub fn send(&self, value: T) -> usize {
+        let mut st = self.shared.state.lock().unwrap();
+        for (_, q) in st.queues.iter_mut() {
+            q.push_back(value.clone());
+        }
+        let n = st.queues.len();
+        drop(st); // release the lock before waking receivers
+        self.shared.ready.notify_all();
+        n
+    }
+}
+
+impl<T: Clone> Default for Broadcast<T> {
+    fn default() -> Self { Self::new() }
+}
+
+impl<T: Clone> Drop for Broadcast<T> {
+    fn drop(&mut self) {
+        self.shared.state.lock().unwrap().closed = true;
+        self.shared.ready.notify_all(); // wake blocked receivers so they see `closed`
+    }
+}
+
+impl<T: Clone> Subscriber<T> {
+    /// Block until a message arrives; `None` once the sender is gone and the queue is drained.
+    pub fn recv(&self) -> Option<T> {
+        let mut st = self.shared.state.lock().unwrap();
+        loop {
+            let closed = st.closed;
+            let q = &mut st.queues.iter_mut().find(|(id, _)| *id == self.id)?.1;
+            if let Some(v) = q.pop_front() {
+                return Some(v);
+            }
+            if closed {
+                return None;
+            }
+            st = self.shared.ready.wait(st).unwrap(); // re-check after every wakeup
+        }
+    }
+
+    pub fn try_recv(&self) -> Option<T> {
+        let mut st = self.shared.state.lock().unwrap();
+        st.queues.iter_mut().find(|(id, _)| *id == self.id)?.1.pop_front()
+    }
+}
+
+impl<T: Clone> Drop for Subscriber<T> {
+    fn drop(&mut self) {
+        // Free our slot so senders stop cloning into a queue nobody reads.
+        let mut st = self.shared.state.lock().unwrap();
+        st.queues.retain(|(id, _)| *id != self.id);
+    }
+}
+
+pub fn example_broadcast_channel() {
+    println!("\n== Example 9: Broadcast channel with Arc<Mutex> + Condvar ==");
+    let tx: Broadcast<String> = Broadcast::new();
+    let (a, b) = (tx.subscribe(), tx.subscribe());
+
+    // Two subscribers on other threads block in recv() until the message arrives.
+    let ha = thread::spawn(move || a.recv());
+    let hb = thread::spawn(move || b.recv());
+    thread::sleep(Duration::from_millis(10)); // let them start waiting (not required)
+    assert_eq!(tx.send("hello".to_string()), 2);
+    let (ra, rb) = (ha.join().unwrap(), hb.join().unwrap());
+    println!("subscriber a got {:?}, b got {:?}", ra, rb);
+    assert_eq!(ra.as_deref(), Some("hello"));
+    assert_eq!(ra, rb);
+    // a and b were moved into the threads and dropped there → their slots are gone.
+
+    // A late subscriber misses everything sent before it subscribed.
+    assert_eq!(tx.send("early".to_string()), 0); // nobody listening
+    let late = tx.subscribe();
+    tx.send("late news".to_string());
+    assert_eq!(late.try_recv().as_deref(), Some("late news"));
+    assert_eq!(late.try_recv(), None);
+    println!("late subscriber only saw messages after subscribe()");
+
+    drop(tx);
+    assert_eq!(late.recv(), None); // sender gone + queue empty → channel closed
+}
+
+pub fn example_arc_cycle_leak() {
+    println!("\n== Example 10: Leaking with an Arc cycle, fixing it with Weak ==");
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+    struct Node {
+        name: &'static str,
+        next: Option<Arc<Mutex<Node>>>, // strong edge
+        prev: Weak<Mutex<Node>>,        // weak edge (only used by the fixed version)
+    }
+    impl Drop for Node {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::SeqCst);
+            println!("  drop({})", self.name);
+        }
+    }
+    let node = |name| Arc::new(Mutex::new(Node { name, next: None, prev: Weak::new() }));
+
+    // Leaky: a -> b -> a, both edges strong.
+    let a = node("a");
+    let b = node("b");
+    a.lock().unwrap().next = Some(b.clone());
+    b.lock().unwrap().next = Some(a.clone());
+    let (probe_a, probe_b) = (Arc::downgrade(&a), Arc::downgrade(&b)); // Weak probes don't add strong refs
+    assert_eq!((Arc::strong_count(&a), Arc::strong_count(&b)), (2, 2));
+
+    drop(a);
+    drop(b); // no outside handles left...
+    println!("after dropping handles: a strong = {}, b strong = {}", probe_a.strong_count(), probe_b.strong_count());
+    assert_eq!((probe_a.strong_count(), probe_b.strong_count()), (1, 1)); // ...yet each keeps the other alive
+    assert_eq!(DROPS.load(Ordering::SeqCst), 0);                         // never freed = leaked
+
+    // Clean up so the demo doesn't actually leak: reach in through a probe and cut one edge.
+    let a = probe_a.upgrade().unwrap();
+    a.lock().unwrap().next = None; // drops b → b drops its strong ref to a
+    drop(a);
+    assert_eq!((probe_a.strong_count(), probe_b.strong_count()), (0, 0));
+    assert_eq!(DROPS.load(Ordering::SeqCst), 2);
+
+    // Fixed: a -> b is strong (ownership), b -> a is Weak (back-reference).
+    DROPS.store(0, Ordering::SeqCst);
+    let a = node("a");
+    let b = node("b");
+    a.lock().unwrap().next = Some(b.clone());
+    b.lock().unwrap().prev = Arc::downgrade(&a);
+    let (probe_a, probe_b) = (Arc::downgrade(&a), Arc::downgrade(&b));
+    assert_eq!((Arc::strong_count(&a), Arc::strong_count(&b)), (1, 2));
+    assert_eq!(b.lock().unwrap().prev.upgrade().unwrap().lock().unwrap().name, "a");
+
+    drop(b); // still owned by a
+    assert_eq!(probe_b.strong_count(), 1);
+    drop(a); // last strong ref to a → a drops → its `next` releases b
+    println!("after dropping handles: a strong = {}, b strong = {}", probe_a.strong_count(), probe_b.strong_count());
+    assert_eq!((probe_a.strong_count(), probe_b.strong_count()), (0, 0));
+    assert!(probe_a.upgrade().is_none() && probe_b.upgrade().is_none());
+    assert_eq!(DROPS.load(Ordering::SeqCst), 2);
+}
+
+/// Shared key/value cache: `Arc` for shared ownership, `RwLock` so lookups from many
+/// threads run in parallel while an insert briefly takes exclusive access.
+///
+/// `get` returns a *clone* of the value, so the read lock is released before the
+/// caller uses it — no guard escapes, and a slow caller never blocks the writer.
+pub struct SharedCache<K: Eq + Hash, V: Clone> {
+    map: Arc<RwLock<HashMap<K, V>>>,
+}
+
+// Manual impl: cloning the handle must not require `K: Clone`.
+impl<K: Eq + Hash, V: Clone> Clone for SharedCache<K, V> {
+    fn clone(&self) -> Self {
+        SharedCache { map: Arc::clone(&self.map) }
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> SharedCache<K, V> {
+    pub fn new() -> Self {
+        SharedCache { map: Arc::new(RwLock::new(HashMap::new())) }
+    }
+
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.map.read().unwrap().get(key).cloned()
+    }
+
+    pub fn insert(&self, key: K, value: V) -> Option<V> {
+        self.map.write().unwrap().insert(key, value)
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.read().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Default for SharedCache<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn example_shared_cache() {
+    println!("\n== Example 11: Shared read-mostly cache (Arc<RwLock<HashMap>>) ==");
+    const KEYS: u64 = 50;
+    let cache: SharedCache<u64, String> = SharedCache::new();
+
+    // Four readers poll until the writer's last key shows up.
+    let readers: Vec<_> = (0..4)
+        .map(|id| {
+            let cache = cache.clone();
+            thread::spawn(move || {
+                let mut lookups = 0u64;
+                loop {
+                    for k in 0..KEYS {
+                        lookups += 1;
+                        if let Some(v) = cache.get(&k) {
+                            assert_eq!(v, format!("value-{k}")); // never a torn/partial value
+                        }
+                    }
+                    if cache.get(&(KEYS - 1)).is_some() {
+                        break;
+                    }
+                    thread::yield_now();
+                }
+                println!("[reader {id}] saw the final key after {lookups} lookups");
+                lookups
+            })
+        })
+        .collect();
+
+    // One occasional writer.
+    let writer = {
+        let cache = cache.clone();
+        thread::spawn(move || {
+            for k in 0..KEYS {
+                cache.insert(k, format!("value-{k}"));
+                if k % 10 == 0 {
+                    thread::sleep(Duration::from_millis(1));
+                }
+            }
+        })
+    };
+
+    writer.join().unwrap();
+    let total: u64 = readers.into_iter().map(|h| h.join().unwrap()).sum(); // all joined → no deadlock
+    assert!(total >= 4 * KEYS);
+    assert_eq!(cache.len(), KEYS as usize);
+    assert_eq!(cache.get(&7).as_deref(), Some("value-7"));
+    assert_eq!(cache.insert(7, "updated".into()).as_deref(), Some("value-7"));
+    assert_eq!(cache.get(&7).as_deref(), Some("updated")); // visible through every clone
+    println!("cache holds {} entries", cache.len());
+}
+
+pub fn example_weak_registry() {
+    println!("\n== Example 12: Weak registry of workers (prune dead via upgrade) ==");
+
+    // Workers are owned by whoever runs them (Arc); the manager only *observes* them.
+    struct Worker {
+        id: usize,
+    }
+
+    // Weak entries don't keep workers alive. A dead entry still pins the allocation
+    // (the Worker itself is already dropped) until `prune` removes it.
+    struct Manager {
+        workers: Vec<Weak<Worker>>,
+    }
+    impl Manager {
+        fn register(&mut self, w: &Arc<Worker>) {
+            self.workers.push(Arc::downgrade(w));
+        }
+        /// Drops entries whose worker is gone; returns how many were removed.
+        fn prune(&mut self) -> usize {
+            let before = self.workers.len();
+            self.workers.retain(|w| w.upgrade().is_some()); // strong_count() > 0 works too
+            before - self.workers.len()
+        }
+        /// Ids of workers alive right now (each upgrade briefly holds a strong ref).
+        fn live_ids(&self) -> Vec<usize> {
+            self.workers.iter().filter_map(Weak::upgrade).map(|w| w.id).collect()
+        }
+    }
+
+    const WORKERS: usize = 10;
+    let mut manager = Manager { workers: Vec::new() };
+    let workers: Vec<Arc<Worker>> = (0..WORKERS).map(|id| Arc::new(Worker { id })).collect();
+    for w in &workers {
+        manager.register(w);
+    }
+    assert_eq!(manager.live_ids(), (0..WORKERS).collect::<Vec<_>>());
+
+    // Move every worker to its own thread. Even ids finish and drop their Arc there;
+    // odd ids hand theirs back through `join`, so they are still alive at the end.
+    let handles: Vec<_> = workers
+        .into_iter()
+        .map(|w| {
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(w.id as u64)); // staggered exits
+                if w.id % 2 == 0 { None } else { Some(w) } // `None` → last strong ref dropped here
+            })
+        })
+        .collect();
+
+    // Prune while the threads are still exiting: each pass removes whoever died so far.
+    let mut pruned = 0;
+    while !handles.iter().all(|h| h.is_finished()) {
+        pruned += manager.prune();
+        thread::sleep(Duration::from_millis(1));
+    }
+    let survivors: Vec<Arc<Worker>> = handles.into_iter().filter_map(|h| h.join().unwrap()).collect();
+    pruned += manager.prune(); // everyone has exited now: one last pass catches the rest
+
+    println!("pruned {pruned} dead workers, live = {:?}", manager.live_ids());
+    assert_eq!(pruned, WORKERS / 2);
+    assert_eq!(manager.workers.len(), survivors.len()); // no dead entries left behind
+    assert_eq!(manager.live_ids(), [1, 3, 5, 7, 9]);
+    assert!(survivors.iter().all(|w| Arc::strong_count(w) == 1 && Arc::weak_count(w) == 1));
+
+    // Dropping the remaining owners makes the rest prunable; the manager never held them up.
+    drop(survivors);
+    assert_eq!(manager.prune(), WORKERS / 2);
+    assert!(manager.workers.is_empty());
+}
+
+/// Countdown latch: starts at `n`; `count_down` decrements, `wait` blocks until it hits 0.
+/// Clones share one counter. Once open it stays open (counting down at 0 is a no-op), so
+/// unlike `std::sync::Barrier` it is single-use, and the waiters don't count towards `n`.
+#[derive(Clone)]
+pub struct CountDownLatch {
+    count: Arc<(Mutex<usize>, Condvar)>,
+}
+
+impl CountDownLatch {
+    pub fn new(n: usize) -> Self {
+        CountDownLatch { count: Arc::new((Mutex::new(n), Condvar::new())) }
+    }
+
+    pub fn count_down(&self) {
+        let (lock, cvar) = &*self.count;
+        let mut n = lock.lock().unwrap();
+        if *n > 0 {
+            *n -= 1;
+            if *n == 0 {
+                cvar.notify_all(); // open: release every waiter at once
+            }
+        }
+    }
+
+    pub fn wait(&self) {
+        let (lock, cvar) = &*self.count;
+        let _open = cvar.wait_while(lock.lock().unwrap(), |n| *n > 0).unwrap(); // handles spurious wakeups
+    }
+
+    pub fn count(&self) -> usize {
+        *self.count.0.lock().unwrap()
+    }
+}
+
+pub fn example_countdown_latch() {
+    println!("\n== Example 13: CountDownLatch (Mutex<usize> + Condvar) ==");
+    const WORKERS: usize = 6;
+    let latch = CountDownLatch::new(WORKERS);
+    let results = Arc::new(Mutex::new(Vec::new()));
+
+    let handles: Vec<_> = (0..WORKERS)
+        .map(|id| {
+            let (latch, results) = (latch.clone(), Arc::clone(&results));
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(5 * id as u64)); // finish at different times
+                results.lock().unwrap().push(id * id); // publish the result *before* signalling
+                latch.count_down();
+            })
+        })
+        .collect();
+
+    latch.wait(); // returns only after all WORKERS have counted down
+    // The Mutex inside the latch orders each push before its count_down, so every
+    // result is visible here even though no worker has been joined yet.
+    let mut got = results.lock().unwrap().clone();
+    got.sort();
+    println!("latch opened with count {}, results = {:?}", latch.count(), got);
+    assert_eq!(latch.count(), 0);
+    assert_eq!(got, (0..WORKERS).map(|i| i * i).collect::<Vec<_>>());
+    for h in handles {
+        h.join().unwrap();
+    }
+
+    // Several waiters are all released by the last count_down; extra count_downs are ignored.
+    let latch = CountDownLatch::new(2);
+    let waiters: Vec<_> = (0..3)
+        .map(|_| {
+            let latch = latch.clone();
+            thread::spawn(move || latch.wait())
+        })
+        .collect();
+    thread::sleep(Duration::from_millis(10));
+    assert!(waiters.iter().all(|w| !w.is_finished())); // nobody gets through at 2
+    latch.count_down();
+    thread::sleep(Duration::from_millis(10));
+    assert!(waiters.iter().all(|w| !w.is_finished())); // ...or at 1
+    latch.count_down();
+    for w in waiters {
+        w.join().unwrap();
+    }
+    latch.count_down(); // already open: stays at 0
+    assert_eq!(latch.count(), 0);
+    CountDownLatch::new(0).wait(); // a zero latch starts open
+}
+
+/// Copy-on-write snapshots: `read` hands out the current `Arc<T>` (a refcount bump, no
+/// copy), `update` builds a new `T` from the current one and swaps in a fresh `Arc`.
+/// Readers that still hold the old `Arc` keep a consistent old value; it is freed when the
+/// last of them drops it. `update` needs `&mut self`, so to share one across threads put it
+/// behind a lock held only for the pointer swap (or use `SharedConfig` from Example 7).
+pub struct Snapshotted<T: Clone> {
+    current: Arc<T>,
+}
+
+impl<T: Clone> Snapshotted<T> {
+    pub fn new(value: T) -> Self {
+        Snapshotted { current: Arc::new(value) }
+    }
+
+    pub fn read(&self) -> Arc<T> {
+        Arc::clone(&self.current)
+    }
+
+    pub fn update(&mut self, f: impl FnOnce(&T) -> T) {
+        self.current = Arc::new(f(&self.current)); // old Arc: one fewer owner
+    }
+}
+
+pub fn example_snapshotted() {
+    println!("\n== Example 14: Copy-on-write snapshots (Snapshotted<T>) ==");
+    let mut routes = Snapshotted::new(vec!["/a".to_string(), "/b".to_string()]);
+
+    let held = routes.read(); // an in-flight request pins this version
+    routes.update(|old| {
+        let mut next = old.clone();
+        next.push("/c".to_string());
+        next
+    });
+    let fresh = routes.read();
+    println!("held = {:?}, fresh = {:?}", held, fresh);
+    assert_eq!(*held, ["/a", "/b"]); // unchanged by the update
+    assert_eq!(*fresh, ["/a", "/b", "/c"]);
+    assert!(!Arc::ptr_eq(&held, &fresh));
+    assert!(Arc::ptr_eq(&fresh, &routes.read())); // reads between updates share one Arc
+
+    // The old version lives exactly as long as its last reader.
+    let old = Arc::downgrade(&held);
+    assert_eq!(old.strong_count(), 1); // only `held`; the Snapshotted let go of it
+    drop(held);
+    assert!(old.upgrade().is_none());
+
+    // Across threads: readers take a snapshot under a brief read lock, then work lock-free.
+    let shared = Arc::new(RwLock::new(Snapshotted::new(0u64)));
+    // Two channels sequence the demo: the reader reports once it holds its snapshot, and
+    // only reads it back after main says the update has been published.
+    let (taken_tx, taken_rx) = std::sync::mpsc::channel();
+    let (updated_tx, updated_rx) = std::sync::mpsc::channel::<()>();
+    let reader = {
+        let shared = Arc::clone(&shared);
+        thread::spawn(move || {
+            let snap = shared.read().unwrap().read(); // lock released at end of statement
+            taken_tx.send(()).unwrap();
+            updated_rx.recv().unwrap(); // writer updates meanwhile
+            *snap
+        })
+    };
+    taken_rx.recv().unwrap();
+    shared.write().unwrap().update(|v| v + 1);
+    updated_tx.send(()).unwrap();
+    assert_eq!(reader.join().unwrap(), 0); // still the version it started with
+    assert_eq!(*shared.read().unwrap().read(), 1);
+}
+
 /*
 Docs-style notes:
 
@@ -195,5 +855,35 @@ Pitfalls:
 - Avoid holding locks longer than needed to prevent contention/deadlocks.
 - Be careful with RwLock writer starvation (implementation-dependent).
 - Weak<T> is essential to break cycles in graph-like structures.
+- Observer lists / registries: hold Vec<Weak<T>> and prune with retain(|w| w.upgrade().is_some());
+  owners may drop their Arc on any thread, the registry just sees upgrade() start failing.
+  A strong cycle is never freed: after the last outside handle is gone each node
+  still has strong_count >= 1 (check with a Weak probe's strong_count()).
+- Poisoning: if a thread panics while holding a Mutex/RwLock guard, later lock()
+  calls return Err(PoisonError). The data is still accessible via into_inner();
+  it matters when a panic can leave the data half-updated (broken invariants).
+  Repair (or discard) the data, then clear_poison() to resume normal unwraps.
+
+Broadcast (Arc<Mutex> + Condvar):
+- Shared state = one queue per subscriber; send() clones into each and notify_all().
+- Receivers wait() in a loop and re-check their queue (spurious wakeups happen).
+- Dropping a subscriber removes its queue; dropping the sender closes the channel.
+
+CountDownLatch (Arc<(Mutex<usize>, Condvar)>):
+- count_down() decrements and notify_all()s at zero; wait() is wait_while(count > 0).
+- One-shot: stays open at 0. Barrier, by contrast, makes the N participants wait for each
+  other and resets for the next round.
+
+Shared cache (SharedCache):
+- Arc<RwLock<HashMap<K, V>>> behind a cloneable handle; get() clones the value out
+  so no guard outlives the call. Fine for small V; for big V store Arc<V> instead.
+
+Read-mostly data (SharedConfig):
+- Arc<RwLock<Arc<T>>> works, but every reader touches the lock.
+- AtomicPtr over Arc::into_raw lets readers clone a snapshot lock-free.
+- Never free the old pointer while a reader may be between load and
+  increment_strong_count; the arc-swap crate solves this more cleverly.
+- Readers register per epoch; a writer flips the epoch and waits only for the
+  readers of the one it closed, so the wait stays bounded under constant reads.
 
 */
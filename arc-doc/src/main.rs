@@ -1,8 +1,16 @@
 use arc_doc::{
+    example_arc_cycle_leak,
     example_atomic_counter,
     example_basic,
+    example_broadcast_channel,
     example_mutation_with_mutex,
+    example_poison_recovery,
     example_rwlock_readers_writers,
+    example_shared_cache,
+    example_weak_registry,
+    example_countdown_latch,
+    example_snapshotted,
+    example_shared_config_swap,
     example_try_unwrap,
     example_weak_to_avoid_cycles,
 };
@@ -14,4 +22,12 @@ fn main() {
     example_atomic_counter();
     example_try_unwrap();
     example_weak_to_avoid_cycles();
+    example_shared_config_swap();
+    example_poison_recovery();
+    example_broadcast_channel();
+    example_arc_cycle_leak();
+    example_shared_cache();
+    example_weak_registry();
+    example_countdown_latch();
+    example_snapshotted();
 }
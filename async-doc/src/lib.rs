@@ -9,13 +9,14 @@
 //! This file demonstrates:
 //!  1) async/await basics
 //!  2) spawning tasks, join handles, JoinSet, cancellation
-//!  3) channels (mpsc / oneshot), async Mutex/RwLock/Notify/Semaphore
-//!  4) timeouts, `select!`, cancellation points
-//!  5) streams
+//!  3) channels (mpsc / oneshot), async Mutex/RwLock/Notify/Semaphore, per-key locks
+//!  4) timeouts, `select!`, cancellation points, racing for the first success, retry on timeout
+//!  5) streams, a bounded multi-stage pipeline (backpressure), batching loads into bulk calls
 //!  6) blocking work offloaded safely
 //!  7) brief internals & API cheat sheet (at bottom)
 
-use futures::{stream, StreamExt};
+use futures::{stream::{self, FuturesUnordered}, StreamExt};
+use std::{collections::HashMap, hash::Hash, sync::Arc};
 use tokio::{
     sync::{mpsc, oneshot, Mutex, RwLock, Notify, Semaphore},
     task::JoinSet,
@@ -168,6 +169,199 @@ pub async fn ex_locks_notify_semaphore() {
     for h in handles { h.await.unwrap(); }
 }
 
+/* ──────────── 5b) Async Mutex fairness: FIFO hand-off vs spinning ──────────── */
+
+pub async fn ex_async_mutex_order() {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    println!("\n== 5b) async Mutex acquisition order (fairness) ==");
+    const TASKS: usize = 4;
+    const ROUNDS: usize = 5;
+
+    // (a) Waiters are served in arrival order: hold the lock, queue tasks one by one.
+    //     With the clock paused, the sleep ends only once task i is parked in the queue.
+    let arrival = tokio::task::spawn_blocking(|| run_paused(async {
+        let lock = Arc::new(Mutex::new(Vec::<usize>::new()));
+        let guard = lock.lock().await;
+        let mut handles = vec![];
+        for i in 0..TASKS {
+            let l = Arc::clone(&lock);
+            handles.push(tokio::spawn(async move { l.lock().await.push(i) }));
+            time::sleep(Duration::from_millis(5)).await; // let task i enqueue before i+1
+        }
+        drop(guard); // release: the lock is handed to the oldest waiter
+        for h in handles { h.await.unwrap(); }
+        lock.lock().await.clone()
+    }))
+    .await
+    .unwrap();
+    println!("arrival-order acquisitions = {:?}", arrival);
+    assert_eq!(arrival, (0..TASKS).collect::<Vec<_>>());
+
+    // (b) Under saturation, each task re-queues behind everyone already waiting,
+    //     so between two of its turns at most TASKS - 1 others get the lock.
+    let log = Arc::new(Mutex::new(Vec::<usize>::new()));
+    let mut handles = vec![];
+    for id in 0..TASKS {
+        let l = Arc::clone(&log);
+        handles.push(tokio::spawn(async move {
+            for _ in 0..ROUNDS {
+                let mut g = l.lock().await;
+                g.push(id);
+                tokio::task::yield_now().await; // hold across a yield → others pile up
+            }
+        }));
+    }
+    let all_done = time::timeout(Duration::from_secs(5), async {
+        for h in handles { h.await.unwrap(); }
+    })
+    .await;
+    assert!(all_done.is_ok(), "a task was starved");
+    let fifo_log = log.lock().await.clone();
+    println!("tokio Mutex turns      = {:?} (max gap {})", fifo_log, max_turn_gap(&fifo_log, TASKS));
+    for id in 0..TASKS {
+        assert_eq!(fifo_log.iter().filter(|&&x| x == id).count(), ROUNDS); // nobody starved
+    }
+    assert!(max_turn_gap(&fifo_log, TASKS) < TASKS);
+
+    // (c) A try-lock spin loop has no queue: whoever retries at the right moment wins,
+    //     so the same task may win repeatedly and the gap is unbounded in principle.
+    let busy = Arc::new(AtomicBool::new(false));
+    let spin_log = Arc::new(std::sync::Mutex::new(Vec::<usize>::new()));
+    let mut handles = vec![];
+    for id in 0..TASKS {
+        let (b, l) = (Arc::clone(&busy), Arc::clone(&spin_log));
+        handles.push(tokio::spawn(async move {
+            for _ in 0..ROUNDS {
+                while b.compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed).is_err() {
+                    tokio::task::yield_now().await;
+                }
+                l.lock().unwrap().push(id);
+                tokio::task::yield_now().await;
+                b.store(false, Ordering::Release);
+            }
+        }));
+    }
+    for h in handles { h.await.unwrap(); }
+    let spin_log = spin_log.lock().unwrap().clone();
+    println!("spin-lock turns        = {:?} (max gap {})", spin_log, max_turn_gap(&spin_log, TASKS));
+}
+
+/// Largest number of other acquisitions between two consecutive turns of one task.
+fn max_turn_gap(log: &[usize], tasks: usize) -> usize {
+    let mut last_seen: Vec<Option<usize>> = vec![None; tasks];
+    let mut max_gap = 0;
+    for (pos, &id) in log.iter().enumerate() {
+        if let Some(prev) = last_seen[id] {
+            max_gap = max_gap.max(pos - prev - 1);
+        }
+        last_seen[id] = Some(pos);
+    }
+    max_gap
+}
+
+/* ──────────── 5c) KeyedMutex: serialize per key, run different keys in parallel ────────────
+One global Mutex would serialize *everything*; one lock per key lets "update user 7" calls
+queue behind each other while "update user 9" proceeds. The map of per-key locks sits behind
+a short-lived std Mutex (never held across `.await`); each entry is an
+`Arc<tokio::sync::Mutex<()>>` so a task can wait on it after releasing the map.
+Cleanup: when a guard drops and nobody else holds (or waits on) that key's `Arc`, the entry
+is removed, so the map only holds keys that are in use.
+*/
+
+pub struct KeyedMutex<K: Eq + Hash + Clone> {
+    locks: std::sync::Mutex<HashMap<K, Arc<Mutex<()>>>>,
+}
+
+/// Holds the lock for one key; dropping it unlocks and prunes the entry if unused.
+pub struct KeyedGuard<'a, K: Eq + Hash + Clone> {
+    owner: &'a KeyedMutex<K>,
+    key: K,
+    guard: Option<tokio::sync::OwnedMutexGuard<()>>,
+}
+
+impl<K: Eq + Hash + Clone> KeyedMutex<K> {
+    pub fn new() -> Self {
+        KeyedMutex { locks: std::sync::Mutex::new(HashMap::new()) }
+    }
+
+    pub async fn lock(&self, key: K) -> KeyedGuard<'_, K> {
+        let entry = {
+            let mut map = self.locks.lock().unwrap();
+            Arc::clone(map.entry(key.clone()).or_default())
+        }; // map released before we (maybe) wait
+        let guard = entry.lock_owned().await;
+        KeyedGuard { owner: self, key, guard: Some(guard) }
+    }
+
+    /// Number of keys currently locked or waited on.
+    pub fn len(&self) -> usize {
+        self.locks.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<K: Eq + Hash + Clone> Default for KeyedMutex<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq + Hash + Clone> Drop for KeyedGuard<'_, K> {
+    fn drop(&mut self) {
+        drop(self.guard.take()); // unlock first (wakes the next waiter, if any)
+        let mut map = self.owner.locks.lock().unwrap();
+        // Waiters clone the Arc under this same map lock, so count == 1 means "map only".
+        if map.get(&self.key).is_some_and(|m| Arc::strong_count(m) == 1) {
+            map.remove(&self.key);
+        }
+    }
+}
+
+pub async fn ex_keyed_mutex() {
+    println!("\n== 5c) KeyedMutex: same key serializes, different keys overlap ==");
+
+    tokio::task::spawn_blocking(|| run_paused(async {
+        const WORK: Duration = Duration::from_millis(100);
+        let locks = Arc::new(KeyedMutex::new());
+        let t0 = time::Instant::now();
+
+        let mut set = JoinSet::new();
+        for (task, key) in ["a", "a", "b", "a"].into_iter().enumerate() {
+            let locks = Arc::clone(&locks);
+            set.spawn(async move {
+                let _g = locks.lock(key).await;
+                let start = t0.elapsed();
+                time::sleep(WORK).await;
+                (task, key, start, t0.elapsed())
+            });
+            tokio::task::yield_now().await; // queue in spawn order
+        }
+        let mut runs = set.join_all().await;
+        runs.sort();
+        for (task, key, start, end) in &runs {
+            println!("task {task} key {key:?}: {start:?} .. {end:?}");
+        }
+
+        let ms = |d: Duration| d.as_millis();
+        let spans: Vec<_> = runs.iter().map(|&(_, k, s, e)| (k, ms(s), ms(e))).collect();
+        // Key "a": back to back, never overlapping. Key "b": alongside the first "a".
+        assert_eq!(spans, [("a", 0, 100), ("a", 100, 200), ("b", 0, 100), ("a", 200, 300)]);
+        assert_eq!(t0.elapsed(), WORK * 3); // not 4×: "b" didn't wait for "a"
+
+        assert!(locks.is_empty(), "entries are pruned once unused");
+        let g = locks.lock("c").await;
+        assert_eq!(locks.len(), 1);
+        drop(g);
+        assert_eq!(locks.len(), 0);
+    }))
+    .await
+    .unwrap();
+}
+
 /* ─────────────── 6) Timeouts, select!, cancellation ─────────────── */
 
 pub async fn ex_timeouts_and_select() {
@@ -202,6 +396,260 @@ async fn work_slow(ms: u64) -> &'static str {
     "ok"
 }
 
+/* ─────────── 6b) Periodic ticks: interval + MissedTickBehavior ─────────── */
+
+pub async fn ex_interval_ticker() {
+    println!("\n== 6b) interval ticker & MissedTickBehavior ==");
+    use tokio::time::MissedTickBehavior;
+
+    // Ticks every 10ms for a 100ms window; the *first* handler is slow (25ms), so
+    // the ticks due at 10ms and 20ms are missed. The behavior decides what happens next:
+    //   Burst: fire missed ticks back-to-back, then stay on the 0,10,20.. grid → 10 ticks
+    //   Delay: fire one tick now, then restart the grid from now (25,35,45..)  →  9 ticks
+    //   Skip:  fire one tick now, then resume on the original grid (30,40..)   →  9 ticks
+    let cases = [
+        (MissedTickBehavior::Burst, 10),
+        (MissedTickBehavior::Delay, 9),
+        (MissedTickBehavior::Skip, 9),
+    ];
+
+    // Each behavior gets its own paused clock, so the tick offsets are exact.
+    let results = tokio::task::spawn_blocking(move || {
+        cases.map(|(behavior, _)| run_paused(count_ticks(behavior)))
+    })
+    .await
+    .unwrap();
+
+    for ((behavior, expected), offsets) in cases.iter().zip(&results) {
+        println!("{behavior:?}: {} ticks at {:?} ms", offsets.len(), offsets);
+        assert_eq!(offsets.len(), *expected, "{behavior:?}");
+    }
+}
+
+async fn count_ticks(behavior: time::MissedTickBehavior) -> Vec<u128> {
+    const PERIOD: Duration = Duration::from_millis(10);
+    const WINDOW: Duration = Duration::from_millis(100);
+
+    let start = time::Instant::now();
+    let mut interval = time::interval(PERIOD); // first tick completes immediately
+    interval.set_missed_tick_behavior(behavior);
+
+    let mut offsets = vec![];
+    loop {
+        interval.tick().await;
+        let at = start.elapsed();
+        if at >= WINDOW { break; }
+        offsets.push(at.as_millis());
+        if offsets.len() == 1 {
+            time::sleep(Duration::from_millis(25)).await; // one slow handler
+        }
+    }
+    offsets
+}
+
+/// Runs `f` to completion on a fresh current-thread runtime with the clock paused: time
+/// only moves when every task is idle, and then jumps straight to the next timer, so
+/// sleeps finish instantly and in exact virtual order. Pausing needs tokio's `test-util`
+/// feature and a current-thread runtime. `block_on` panics inside another runtime, so
+/// call this from a blocking thread (`spawn_blocking(|| run_paused(..))`).
+fn run_paused<F: Future>(f: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .start_paused(true)
+        .build()
+        .unwrap()
+        .block_on(f)
+}
+
+/* ─────────── 6c) race_ok: first success wins, losers are cancelled ─────────── */
+
+/// Resolves to the first `Ok` among `futs`; the remaining futures are dropped (cancelled).
+/// If every future fails, returns all errors in *completion* order (empty input → `Err([])`).
+pub async fn race_ok<T, E>(futs: Vec<impl Future<Output = Result<T, E>>>) -> Result<T, Vec<E>> {
+    // FuturesUnordered polls only the futures that were woken, and yields results as they finish.
+    let mut pending: FuturesUnordered<_> = futs.into_iter().collect();
+    let mut errors = Vec::with_capacity(pending.len());
+    while let Some(res) = pending.next().await {
+        match res {
+            Ok(v) => return Ok(v), // `pending` is dropped here → the slower futures are cancelled
+            Err(e) => errors.push(e),
+        }
+    }
+    Err(errors)
+}
+
+pub async fn ex_race_ok() {
+    println!("\n== 6c) race_ok: first Ok wins, rest cancelled ==");
+    use std::sync::{Arc, atomic::{AtomicUsize, Ordering}};
+
+    tokio::task::spawn_blocking(|| run_paused(async {
+        // One success (20ms) among slower attempts and a fast failure.
+        let finished = Arc::new(AtomicUsize::new(0));
+        let start = time::Instant::now();
+        let res = race_ok(vec![
+            attempt("mirror-a", 50, true, finished.clone()),
+            attempt("mirror-b", 10, false, finished.clone()),
+            attempt("mirror-c", 20, true, finished.clone()),
+            attempt("mirror-d", 80, true, finished.clone()),
+        ])
+        .await;
+        println!("winner: {res:?} after {:?}", start.elapsed());
+        assert_eq!(res, Ok("mirror-c"));
+        assert_eq!(start.elapsed(), Duration::from_millis(20)); // didn't wait for a/d
+        time::sleep(Duration::from_secs(1)).await; // give losers every chance to finish
+        assert_eq!(finished.load(Ordering::SeqCst), 2, "only b and c ran to completion");
+
+        // Every attempt fails: errors come back in the order they happened.
+        let start = time::Instant::now();
+        let res = race_ok(vec![
+            attempt("mirror-a", 30, false, finished.clone()),
+            attempt("mirror-b", 10, false, finished.clone()),
+            attempt("mirror-c", 20, false, finished.clone()),
+        ])
+        .await;
+        println!("all failed: {res:?} after {:?}", start.elapsed());
+        assert_eq!(res, Err(vec!["mirror-b", "mirror-c", "mirror-a"]));
+        assert_eq!(start.elapsed(), Duration::from_millis(30));
+    }))
+    .await
+    .unwrap();
+}
+
+async fn attempt(
+    name: &'static str,
+    ms: u64,
+    ok: bool,
+    finished: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+) -> Result<&'static str, &'static str> {
+    time::sleep(Duration::from_millis(ms)).await; // a cancelled attempt never gets past here
+    finished.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    if ok { Ok(name) } else { Err(name) } // Err carries who failed
+}
+
+/* ─────────── 6d) Cooperative cancellation: select! on a stop channel ─────────── */
+
+pub async fn ex_cancellable_loop() {
+    println!("\n== 6d) cancellable read loop (mpsc + oneshot stop) ==");
+
+    // Cooperative stop: the worker sees the signal at an `.await`, finishes what is
+    // already queued, and returns a summary.
+    let (tx, rx) = mpsc::channel::<u32>(16);
+    let (stop_tx, stop_rx) = oneshot::channel::<()>();
+    let worker = tokio::spawn(read_until_stopped(rx, stop_rx));
+
+    for x in [1, 2] { tx.send(x).await.unwrap(); }
+    time::sleep(Duration::from_millis(5)).await; // worker handles these inside the loop
+    for x in [3, 4, 5] { tx.send(x).await.unwrap(); } // still buffered when stop fires
+    stop_tx.send(()).unwrap();
+
+    let (processed, sum) = worker.await.unwrap();
+    println!("stopped cleanly: processed {processed} items, sum = {sum}");
+    assert_eq!((processed, sum), (5, 15)); // nothing queued before the stop was lost
+    assert!(tx.send(6).await.is_err());   // receiver gone: the loop really exited
+    // (`tx` is still alive, so the exit came from the stop signal, not a closed channel.)
+
+    // Non-cooperative: abort() cancels the task at its next `.await`. There is no
+    // chance to drain the queue and no return value — just a JoinError.
+    let (tx, rx) = mpsc::channel::<u32>(16);
+    let (_stop_tx, stop_rx) = oneshot::channel::<()>();
+    let worker = tokio::spawn(read_until_stopped(rx, stop_rx));
+    for x in [1, 2, 3] { tx.send(x).await.unwrap(); }
+    worker.abort();
+    let res = worker.await;
+    println!("aborted: {:?}", res.as_ref().map_err(|e| e.is_cancelled()));
+    assert!(res.unwrap_err().is_cancelled()); // no summary, buffered items dropped
+}
+
+/// Reads items until `stop` fires (or every sender is gone); returns (count, sum).
+async fn read_until_stopped(mut rx: mpsc::Receiver<u32>, mut stop: oneshot::Receiver<()>) -> (usize, u32) {
+    let (mut count, mut sum) = (0, 0);
+    loop {
+        tokio::select! {
+            biased; // poll `stop` first, so a pending signal wins over more items
+            _ = &mut stop => {
+                // Drain what was already queued, then exit; no new waiting.
+                while let Ok(x) = rx.try_recv() {
+                    count += 1;
+                    sum += x;
+                }
+                break;
+            }
+            item = rx.recv() => match item {
+                Some(x) => {
+                    count += 1;
+                    sum += x;
+                }
+                None => break, // all senders dropped
+            },
+        }
+    }
+    (count, sum)
+}
+
+/* ─────────── 6e) timeout_retry: bounded attempts, each under a deadline ─────────── */
+
+/// Runs `f()` under `per_attempt`; on timeout drops that attempt (cancelling it) and starts
+/// a fresh one, at most `attempts` times. `Some` on the first attempt that finishes in time,
+/// `None` if all of them time out (or `attempts == 0`).
+pub async fn timeout_retry<F, Fut, T>(attempts: u32, per_attempt: Duration, mut f: F) -> Option<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = T>,
+{
+    for _ in 0..attempts {
+        if let Ok(v) = time::timeout(per_attempt, f()).await {
+            return Some(v);
+        }
+    }
+    None
+}
+
+pub async fn ex_timeout_retry() {
+    println!("\n== 6e) timeout_retry: retry attempts that exceed a deadline ==");
+    use std::sync::{Arc, atomic::{AtomicU32, Ordering}};
+
+    tokio::task::spawn_blocking(|| run_paused(async {
+        const DEADLINE: Duration = Duration::from_millis(100);
+
+        // The first attempt hangs (300ms), the second answers in 30ms.
+        let calls = Arc::new(AtomicU32::new(0));
+        let start = time::Instant::now();
+        let c = calls.clone();
+        let res = timeout_retry(3, DEADLINE, || {
+            let n = c.fetch_add(1, Ordering::SeqCst) + 1;
+            async move {
+                time::sleep(Duration::from_millis(if n == 1 { 300 } else { 30 })).await;
+                format!("reply from attempt {n}")
+            }
+        })
+        .await;
+        println!("{res:?} after {:?}", start.elapsed());
+        assert_eq!(res.as_deref(), Some("reply from attempt 2"));
+        assert_eq!(calls.load(Ordering::SeqCst), 2); // no third attempt
+        assert_eq!(start.elapsed(), DEADLINE + Duration::from_millis(30));
+
+        // Every attempt is too slow: give up after exactly `attempts` deadlines.
+        calls.store(0, Ordering::SeqCst);
+        let start = time::Instant::now();
+        let c = calls.clone();
+        let res = timeout_retry(4, DEADLINE, || {
+            c.fetch_add(1, Ordering::SeqCst);
+            time::sleep(Duration::from_secs(1))
+        })
+        .await;
+        println!("{res:?} after {:?}", start.elapsed());
+        assert_eq!(res, None);
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+        assert_eq!(start.elapsed(), DEADLINE * 4);
+
+        // Fast path: first try succeeds, nothing retried; zero attempts never calls `f`.
+        assert_eq!(timeout_retry(3, DEADLINE, || async { 7 }).await, Some(7));
+        assert_eq!(timeout_retry(0, DEADLINE, || async { unreachable!() }).await, None::<()>);
+    }))
+    .await
+    .unwrap();
+}
+
 /* ───────────────────────── 7) Streams ───────────────────────── */
 
 pub async fn ex_streams() {
@@ -216,6 +664,190 @@ pub async fn ex_streams() {
     println!("squares via stream = {:?}", out);
 }
 
+/* ─────────── 7b) Pipeline: bounded stages + buffer_unordered fetch ─────────── */
+
+pub async fn ex_pipeline() {
+    println!("\n== 7b) pipeline: producer → fetch (buffer_unordered) → process ==");
+    use std::sync::{Arc, atomic::{AtomicUsize, Ordering}};
+
+    const ITEMS: u32 = 20;
+    const URL_CAP: usize = 2;    // producer → fetch channel
+    const IN_FLIGHT: usize = 3;  // concurrent fetches
+    const OUT_CAP: usize = 2;    // fetch → process channel
+
+    tokio::task::spawn_blocking(|| run_paused(async {
+        let produced = Arc::new(AtomicUsize::new(0));
+        let start = time::Instant::now();
+
+        // Stage 0: producer. `send().await` parks once `URL_CAP` items are waiting.
+        let (url_tx, url_rx) = mpsc::channel::<u32>(URL_CAP);
+        let p = produced.clone();
+        let producer = tokio::spawn(async move {
+            for id in 1..=ITEMS {
+                url_tx.send(id).await.unwrap();
+                p.fetch_add(1, Ordering::SeqCst);
+            }
+            start.elapsed() // when the last item got into the pipe
+        });
+
+        // Stage 1: fetch, at most `IN_FLIGHT` at a time, outputs in completion order.
+        // If `out_tx` is full the loop stops polling the stream → no new fetches start.
+        let (out_tx, mut out_rx) = mpsc::channel::<(u32, usize)>(OUT_CAP);
+        let fetcher = tokio::spawn(async move {
+            let mut url_rx = url_rx;
+            let urls = stream::poll_fn(move |cx| url_rx.poll_recv(cx)); // Receiver → Stream
+            let mut fetched = urls.map(fetch_page).buffer_unordered(IN_FLIGHT);
+            while let Some(page) = fetched.next().await {
+                out_tx.send(page).await.unwrap();
+            }
+            // `out_tx` dropped here → the processing stage sees the end of input
+        });
+
+        // Stage 2: a slow consumer (20ms per item) — the bottleneck of the pipeline.
+        let (mut results, mut max_lag) = (vec![], 0);
+        while let Some((id, len)) = out_rx.recv().await {
+            time::sleep(Duration::from_millis(20)).await;
+            results.push((id, len * 2));
+            max_lag = max_lag.max(produced.load(Ordering::SeqCst) - results.len());
+        }
+        let producer_done = producer.await.unwrap();
+        fetcher.await.unwrap();
+        let total = start.elapsed();
+
+        results.sort();
+        println!("processed {} items in {total:?}; producer finished at {producer_done:?}, max lag {max_lag}",
+            results.len());
+        // Complete: every id went through both stages exactly once.
+        let expected: Vec<_> = (1..=ITEMS).map(|id| (id, page_len(id) * 2)).collect();
+        assert_eq!(results, expected);
+        // Throttled: the producer is never further ahead than the buffers allow
+        // (both channels + in-flight fetches + one item parked in each stage's hand)...
+        assert!(max_lag <= URL_CAP + IN_FLIGHT + OUT_CAP + 2, "lag {max_lag}");
+        // ...so it finishes near the end instead of dumping all items at t = 0.
+        assert!(producer_done >= total / 2, "{producer_done:?} vs {total:?}");
+        // The consumer is the bottleneck: total ≈ ITEMS × 20ms (+ the first fetch).
+        assert!(total >= Duration::from_millis(20 * ITEMS as u64));
+    }))
+    .await
+    .unwrap();
+}
+
+fn page_len(id: u32) -> usize {
+    100 + id as usize
+}
+
+async fn fetch_page(id: u32) -> (u32, usize) {
+    time::sleep(Duration::from_millis(5 + (id as u64 * 7) % 25)).await; // uneven latencies
+    (id, page_len(id))
+}
+
+/* ─────────── 7c) BatchLoader: coalesce single loads into bulk fetches ───────────
+DataLoader pattern: code that naturally asks for one key at a time (`load(7).await`) ends
+up issuing one bulk `fetch(keys)` per short window (think one `WHERE id IN (..)` query
+instead of N round trips). Each `load` sends `(key, oneshot::Sender)` to a batching task
+and awaits the oneshot. The task waits for the first request, collects more until the
+window closes, fetches the distinct keys once, and answers every waiter from the result.
+A key missing from the result (or a loader that has shut down) resolves to `None`.
+*/
+
+pub struct BatchLoader<K, V> {
+    requests: mpsc::UnboundedSender<(K, oneshot::Sender<Option<V>>)>,
+}
+
+impl<K, V> Clone for BatchLoader<K, V> {
+    fn clone(&self) -> Self {
+        BatchLoader { requests: self.requests.clone() }
+    }
+}
+
+impl<K, V> BatchLoader<K, V>
+where
+    K: Eq + Hash + Clone + Send + 'static,
+    V: Clone + Send + 'static,
+{
+    /// Spawns the batching task (call inside a runtime). It exits when every clone is dropped.
+    pub fn new<F, Fut>(window: Duration, fetch: F) -> Self
+    where
+        F: Fn(Vec<K>) -> Fut + Send + 'static,
+        Fut: Future<Output = HashMap<K, V>> + Send,
+    {
+        let (requests, mut rx) = mpsc::unbounded_channel::<(K, oneshot::Sender<Option<V>>)>();
+        tokio::spawn(async move {
+            while let Some(first) = rx.recv().await {
+                let mut batch = vec![first];
+                let close = time::sleep(window);
+                tokio::pin!(close);
+                loop {
+                    tokio::select! {
+                        _ = &mut close => break,
+                        req = rx.recv() => match req {
+                            Some(req) => batch.push(req),
+                            None => break, // all handles gone: flush what we have
+                        },
+                    }
+                }
+                let mut keys: Vec<K> = Vec::with_capacity(batch.len());
+                for (k, _) in &batch {
+                    if !keys.contains(k) {
+                        keys.push(k.clone()); // distinct, in arrival order
+                    }
+                }
+                let found = fetch(keys).await;
+                for (k, reply) in batch {
+                    let _ = reply.send(found.get(&k).cloned()); // caller may have given up
+                }
+            }
+        });
+        BatchLoader { requests }
+    }
+
+    pub async fn load(&self, key: K) -> Option<V> {
+        let (tx, rx) = oneshot::channel();
+        self.requests.send((key, tx)).ok()?;
+        rx.await.ok().flatten()
+    }
+}
+
+pub async fn ex_batch_loader() {
+    println!("\n== 7c) BatchLoader: 5 concurrent loads → 1 bulk fetch ==");
+
+    tokio::task::spawn_blocking(|| run_paused(async {
+        const WINDOW: Duration = Duration::from_millis(5);
+        let calls: Arc<std::sync::Mutex<Vec<Vec<u32>>>> = Arc::default();
+        let log = Arc::clone(&calls);
+        let loader = BatchLoader::new(WINDOW, move |keys: Vec<u32>| {
+            log.lock().unwrap().push(keys.clone());
+            async move {
+                time::sleep(Duration::from_millis(20)).await; // one round trip for all
+                keys.into_iter().filter(|k| *k != 404).map(|k| (k, format!("user-{k}"))).collect()
+            }
+        });
+
+        let start = time::Instant::now();
+        let mut set = JoinSet::new();
+        for id in [3, 1, 4, 5, 2] {
+            let loader = loader.clone();
+            set.spawn(async move { (id, loader.load(id).await) });
+        }
+        let mut got = set.join_all().await;
+        got.sort();
+        println!("results {got:?} after {:?}; fetch calls {:?}", start.elapsed(), calls.lock().unwrap());
+        let mut batched = calls.lock().unwrap().clone();
+        assert_eq!(batched.len(), 1); // one bulk call...
+        batched[0].sort();
+        assert_eq!(batched[0], [1, 2, 3, 4, 5]); // ...with all five keys
+        assert_eq!(got, (1..=5).map(|k| (k, Some(format!("user-{k}")))).collect::<Vec<_>>());
+        assert_eq!(start.elapsed(), WINDOW + Duration::from_millis(20));
+
+        // Later loads form a new batch; duplicate keys are fetched once, missing ones → None.
+        let (a, b, missing) = tokio::join!(loader.load(7), loader.load(7), loader.load(404));
+        assert_eq!((a.as_deref(), b.as_deref(), missing), (Some("user-7"), Some("user-7"), None));
+        assert_eq!(calls.lock().unwrap()[1], [7, 404]);
+    }))
+    .await
+    .unwrap();
+}
+
 /* ─────────────── 8) Offloading blocking work safely ─────────────── */
 
 pub async fn ex_blocking_work() {
@@ -248,10 +880,15 @@ DO & DON’T
 LOCKS IN ASYNC
 - Use `tokio::sync::Mutex/RwLock`: `lock().await` returns a guard; keep lock scope small; drop before `.await`ing other things.
 - For simple counters/flags use atomics on a shared `Arc<Atomic*>` (works fine inside async).
+- `tokio::sync::Mutex` is fair: waiters queue FIFO and the lock is handed to the oldest one,
+  so no task starves. A hand-rolled try-lock/spin loop has no queue and no such guarantee.
 
 CONCURRENCY PRIMITIVES (Tokio)
 - Tasks: `tokio::spawn`, `JoinSet`, `JoinHandle::abort`.
 - Time: `tokio::time::{sleep, timeout, interval}`.
+  `interval` + `MissedTickBehavior::{Burst, Delay, Skip}` decides how ticks recover after a slow
+  handler: catch up back-to-back (default), restart the schedule from now, or jump to the next slot.
+  Tests can use paused time (`test-util` feature, `start_paused(true)`) for exact, instant timing.
 - Select: `tokio::select!` to await whichever future completes first.
 - Channels: `mpsc` (multi-producer), `oneshot` (single value).
 - Sync: `Mutex`, `RwLock`, `Notify` (wakeup), `Semaphore` (permits).
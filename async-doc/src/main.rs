@@ -4,8 +4,16 @@ use async_doc::{
     ex_joinset_and_cancel,
     ex_channels,
     ex_locks_notify_semaphore,
+    ex_async_mutex_order,
+    ex_keyed_mutex,
     ex_timeouts_and_select,
+    ex_interval_ticker,
+    ex_race_ok,
+    ex_cancellable_loop,
+    ex_timeout_retry,
     ex_streams,
+    ex_pipeline,
+    ex_batch_loader,
     ex_blocking_work,
 };
 
@@ -16,7 +24,15 @@ async fn main() {
     ex_joinset_and_cancel().await;
     ex_channels().await;
     ex_locks_notify_semaphore().await;
+    ex_async_mutex_order().await;
+    ex_keyed_mutex().await;
     ex_timeouts_and_select().await;
+    ex_interval_ticker().await;
+    ex_race_ok().await;
+    ex_cancellable_loop().await;
+    ex_timeout_retry().await;
     ex_streams().await;
+    ex_pipeline().await;
+    ex_batch_loader().await;
     ex_blocking_work().await;
 }
@@ -12,15 +12,25 @@
 //!  3) `compare_exchange` patterns (one-time init / CAS loop)
 //!  4) AtomicPtr and fences
 //!  5) AtomicCell<T> ergonomics (load/store/swap/update)
-//!  6) Cheatsheet + pitfalls (in comments)
+//!  6) MiniArc<T>: reference counting by hand (Relaxed clone, Release drop + Acquire fence)
+//!  7) Epoch-based reclamation with crossbeam::epoch (lock-free stack, defer_destroy)
+//!  8) ShardedCounter: cache-line padded per-thread shards for contended counters
+//!  9) Tagged pointers: a generation counter packed into an AtomicU64 (ABA mitigation)
+//! 10) Lazy<T>: double-checked locking (Acquire fast path + Mutex slow path) vs std Once
+//! 11) fetch_max / fetch_min as CAS loops (extension trait on AtomicU64)
+//! 12) SeqGen: unique IDs via fetch_add, plus a CAS variant that detects wraparound
+//! 13) AtomicReservoir<K>: lock-free (approximately fair) reservoir sampling
+//! 14) Cheatsheet + pitfalls (in comments)
 
 use std::{
+    cell::UnsafeCell,
+    mem::{ManuallyDrop, MaybeUninit},
     ptr::NonNull,
     sync::{
         atomic::{
             fence, AtomicBool, AtomicPtr, AtomicU64, AtomicUsize, Ordering::{self, *}
         },
-        Arc,
+        Arc, Mutex,
     },
     thread,
     time::Duration,
@@ -28,6 +38,8 @@ use std::{
 
 // Crossbeam's AtomicCell:
 use crossbeam::atomic::AtomicCell;
+// Crossbeam's epoch-based memory reclamation:
+use crossbeam::epoch::{self, Atomic, Owned};
 
 /* ───────────────────────── 1) Counter (Relaxed) ─────────────────────────
 Relaxed operations are fine when you only need a number to be correct,
@@ -206,6 +218,803 @@ impl FetchAdd for AtomicCell<u64> {
 }
 
 
+/* ─────────────── 6) MiniArc<T>: atomic refcounting by hand ───────────────
+A stripped-down `Arc<T>` to show *why* std picks its orderings:
+- clone: `fetch_add(1, Relaxed)` — we already hold a reference, so the object can't
+  vanish under us; the increment publishes nothing, it only has to be atomic.
+- drop:  `fetch_sub(1, Release)` — every owner's last uses of `T` happen-before its
+  decrement. The owner that sees the count hit 1 (→ 0) then issues `fence(Acquire)`,
+  synchronizing with all those Release decrements before freeing. Without the fence
+  the free could race with another thread's final read of `T`.
+*/
+struct MiniArcInner<T> {
+    count: AtomicUsize,
+    value: T,
+}
+
+pub struct MiniArc<T> {
+    ptr: NonNull<MiniArcInner<T>>,
+}
+
+// Same bounds as std: sharing &T across threads and dropping T on any thread.
+unsafe impl<T: Send + Sync> Send for MiniArc<T> {}
+unsafe impl<T: Send + Sync> Sync for MiniArc<T> {}
+
+impl<T> MiniArc<T> {
+    pub fn new(value: T) -> Self {
+        let inner = Box::new(MiniArcInner { count: AtomicUsize::new(1), value });
+        MiniArc { ptr: NonNull::from(Box::leak(inner)) }
+    }
+
+    fn inner(&self) -> &MiniArcInner<T> {
+        // SAFETY: the allocation lives while any MiniArc (us included) exists.
+        unsafe { self.ptr.as_ref() }
+    }
+
+    pub fn strong_count(this: &Self) -> usize {
+        this.inner().count.load(Relaxed)
+    }
+}
+
+impl<T> Clone for MiniArc<T> {
+    fn clone(&self) -> Self {
+        // Relaxed: new references can only be made from existing ones.
+        let old = self.inner().count.fetch_add(1, Relaxed);
+        // std aborts on overflow (e.g. clones leaked via mem::forget).
+        if old > usize::MAX / 2 {
+            std::process::abort();
+        }
+        MiniArc { ptr: self.ptr }
+    }
+}
+
+impl<T> std::ops::Deref for MiniArc<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.inner().value
+    }
+}
+
+impl<T> Drop for MiniArc<T> {
+    fn drop(&mut self) {
+        // Release: our uses of `value` must be visible to whoever frees it.
+        if self.inner().count.fetch_sub(1, Release) != 1 {
+            return;
+        }
+        // Acquire: see every other owner's uses before we free.
+        fence(Acquire);
+        // SAFETY: count reached zero; we are the last owner.
+        unsafe { drop(Box::from_raw(self.ptr.as_ptr())) };
+    }
+}
+
+pub fn ex_mini_arc() {
+    println!("\n== 6) MiniArc<T>: hand-rolled atomic refcount ==");
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+    struct DropCounter(u64);
+    impl Drop for DropCounter {
+        fn drop(&mut self) { DROPS.fetch_add(1, Relaxed); }
+    }
+
+    let shared = MiniArc::new(DropCounter(7));
+    let mut handles = vec![];
+    for _ in 0..4 {
+        let local = shared.clone();
+        handles.push(thread::spawn(move || {
+            let mut sum = 0;
+            for _ in 0..1_000 {
+                let c = local.clone(); // clone + drop on this thread
+                sum += c.0;
+            }
+            sum
+        }));
+    }
+    println!("strong_count while threads run <= {}", MiniArc::strong_count(&shared));
+    for h in handles { assert_eq!(h.join().unwrap(), 7_000); }
+
+    assert_eq!(MiniArc::strong_count(&shared), 1);
+    assert_eq!(DROPS.load(Relaxed), 0); // still alive: we hold the last ref
+    drop(shared);
+    assert_eq!(DROPS.load(Relaxed), 1); // freed exactly once
+    println!("inner dropped {} time(s)", DROPS.load(Relaxed));
+}
+
+/* ─────────────── 7) Epoch-based reclamation (crossbeam::epoch) ───────────────
+In a lock-free stack, `pop` unlinks the head node — but another thread may have loaded
+the same head pointer a moment earlier and is about to read `head.next`. Freeing the node
+immediately would be a use-after-free. Hazard pointers solve this by having readers
+*announce* which pointers they use; epochs are coarser and cheaper:
+- every operation runs inside `epoch::pin()` (a guard marking "I may hold pointers"),
+- an unlinked node is handed to `guard.defer_destroy(ptr)` instead of being freed,
+- crossbeam frees it only once every thread pinned at unlink time has unpinned.
+*/
+struct Node<T> {
+    // ManuallyDrop: `pop` moves the value out; the deferred free must not drop it again.
+    data: ManuallyDrop<T>,
+    next: Atomic<Node<T>>,
+}
+
+/// Treiber stack whose nodes are reclaimed through crossbeam's epochs.
+pub struct EpochStack<T> {
+    head: Atomic<Node<T>>,
+}
+
+impl<T> EpochStack<T> {
+    pub fn new() -> Self {
+        EpochStack { head: Atomic::null() }
+    }
+
+    pub fn push(&self, value: T) {
+        let mut node = Owned::new(Node { data: ManuallyDrop::new(value), next: Atomic::null() });
+        let guard = epoch::pin();
+        loop {
+            let head = self.head.load(Relaxed, &guard);
+            node.next.store(head, Relaxed);
+            // Release: publish the node's contents together with the new head.
+            match self.head.compare_exchange(head, node, Release, Relaxed, &guard) {
+                Ok(_) => return,
+                Err(e) => node = e.new, // lost the race; retry with our node back
+            }
+        }
+    }
+
+    pub fn pop(&self) -> Option<T> {
+        let guard = epoch::pin();
+        loop {
+            let head = self.head.load(Acquire, &guard);
+            // SAFETY: while `guard` is pinned, nodes reachable at load time stay allocated.
+            let node = unsafe { head.as_ref() }?;
+            let next = node.next.load(Relaxed, &guard);
+            if self.head.compare_exchange(head, next, Relaxed, Relaxed, &guard).is_ok() {
+                // SAFETY: we unlinked `head`, so we alone take its data, and we defer
+                // the free until no pinned thread can still be reading the node.
+                unsafe {
+                    let value = ManuallyDrop::into_inner(std::ptr::read(&node.data));
+                    guard.defer_destroy(head);
+                    return Some(value);
+                }
+            }
+        }
+    }
+}
+
+impl<T> Default for EpochStack<T> {
+    fn default() -> Self { Self::new() }
+}
+
+impl<T> Drop for EpochStack<T> {
+    fn drop(&mut self) {
+        while self.pop().is_some() {} // drain so every remaining value is dropped once
+    }
+}
+
+pub fn ex_epoch_reclaim() {
+    println!("\n== 7) Epoch-based reclamation: lock-free stack ==");
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+    struct Tracked(u64);
+    impl Drop for Tracked {
+        fn drop(&mut self) { DROPS.fetch_add(1, Relaxed); }
+    }
+
+    const THREADS: u64 = 4;
+    const PER_THREAD: u64 = 10_000;
+    for round in 0..3 {
+        DROPS.store(0, Relaxed);
+        let stack = Arc::new(EpochStack::new());
+        let popped_sum = Arc::new(AtomicU64::new(0));
+        let mut handles = vec![];
+        for t in 0..THREADS {
+            let (s, sum) = (stack.clone(), popped_sum.clone());
+            handles.push(thread::spawn(move || {
+                for i in 0..PER_THREAD {
+                    s.push(Tracked(t * PER_THREAD + i));
+                    if i % 2 == 0 {
+                        // Pop concurrently with other pushers/poppers.
+                        if let Some(v) = s.pop() { sum.fetch_add(v.0, Relaxed); }
+                    }
+                }
+            }));
+        }
+        for h in handles { h.join().unwrap(); }
+        while let Some(v) = stack.pop() { popped_sum.fetch_add(v.0, Relaxed); }
+
+        let n = THREADS * PER_THREAD;
+        assert_eq!(popped_sum.load(Relaxed), n * (n - 1) / 2); // every value popped exactly once
+        assert_eq!(DROPS.load(Relaxed) as u64, n);              // and dropped exactly once
+        println!("round {round}: {n} values pushed/popped, {} drops", DROPS.load(Relaxed));
+    }
+    // For extra assurance run with a sanitizer, e.g.
+    //   RUSTFLAGS="-Zsanitizer=address" cargo +nightly run --target x86_64-unknown-linux-gnu
+}
+
+/* ─────────────── 8) ShardedCounter: per-thread shards for hot counters ───────────────
+A single `AtomicU64` bumped by every thread is correct but slow under load: each
+`fetch_add` needs the cache line in *exclusive* state, so the line ping-pongs between
+cores and throughput drops as threads are added. Sharding spreads the writes:
+- each thread picks a shard from its thread id and only `fetch_add`s there (Relaxed,
+  wait-free on common hardware: one instruction, no retry loop),
+- `sum()` walks all shards — reads are rarer, so paying O(shards) there is the trade.
+Each shard is `#[repr(align(64))]` so two shards never share a cache line; otherwise
+neighbouring shards would *false-share* and contend just like the single counter.
+`sum()` is not a snapshot: concurrent increments may or may not be included.
+*/
+#[repr(align(64))]
+#[derive(Default)]
+struct PaddedU64(AtomicU64);
+
+pub struct ShardedCounter {
+    shards: Box<[PaddedU64]>,
+}
+
+impl ShardedCounter {
+    pub fn new(shards: usize) -> Self {
+        assert!(shards > 0, "need at least one shard");
+        ShardedCounter { shards: (0..shards).map(|_| PaddedU64::default()).collect() }
+    }
+
+    fn shard_index(&self) -> usize {
+        use std::hash::{BuildHasher, RandomState};
+        thread_local! {
+            // Hash the ThreadId once per thread; `ThreadId::as_u64` is still unstable.
+            static ID_HASH: usize = RandomState::new().hash_one(thread::current().id()) as usize;
+        }
+        ID_HASH.with(|h| *h) % self.shards.len()
+    }
+
+    pub fn add(&self, n: u64) {
+        self.shards[self.shard_index()].0.fetch_add(n, Relaxed);
+    }
+
+    pub fn inc(&self) {
+        self.add(1);
+    }
+
+    pub fn sum(&self) -> u64 {
+        self.shards.iter().map(|s| s.0.load(Relaxed)).sum()
+    }
+}
+
+pub fn ex_sharded_counter() {
+    println!("\n== 8) ShardedCounter: per-thread shards, cache-line padded ==");
+    assert_eq!(std::mem::align_of::<PaddedU64>(), 64);
+    assert_eq!(std::mem::size_of::<PaddedU64>(), 64); // one shard per cache line
+
+    const THREADS: u64 = 8;
+    const PER_THREAD: u64 = 100_000;
+    let counter = Arc::new(ShardedCounter::new(THREADS as usize));
+    let handles: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let c = counter.clone();
+            thread::spawn(move || {
+                for _ in 0..PER_THREAD { c.inc(); }
+            })
+        })
+        .collect();
+    for h in handles { h.join().unwrap(); }
+
+    // join() synchronizes with each thread, so every increment is visible now.
+    assert_eq!(counter.sum(), THREADS * PER_THREAD);
+    println!("sum over {} shards = {}", counter.shards.len(), counter.sum());
+}
+
+/* ─────────────── 9) Tagged pointers: a generation counter against ABA ───────────────
+ABA: thread 1 reads head = A and is preempted; others pop A, pop B, push A back (same
+address, maybe reused memory). Thread 1's `compare_exchange(A, ..)` succeeds, though the
+structure changed underneath it. Fix: store a *generation tag* next to the pointer and bump
+it on every successful CAS — the word is then (A, 0) vs (A, 2), and the stale CAS fails.
+
+Packing into one `AtomicU64` (so one CAS covers pointer + tag) assumes:
+- 64-bit target with 48-bit user-space virtual addresses (x86_64 / aarch64 today):
+  bits 48..64 of a user pointer are zero, so they can carry a `u16` tag.
+  (5-level paging / pointer authentication break this; check with `debug_assert`.)
+- Alternatively, alignment frees the *low* bits (an 8-aligned pointer has 3 zero bits),
+  but only 3 tag bits wrap far too quickly to be a generation counter.
+- A u16 tag still wraps after 65_536 updates: it makes ABA unlikely, not impossible.
+- The pointer goes through an integer, so we use `expose_provenance` /
+  `with_exposed_provenance_mut` to keep the round-trip well-defined (Miri-friendly).
+*/
+const TAG_SHIFT: u32 = 48;
+const ADDR_MASK: u64 = (1 << TAG_SHIFT) - 1;
+
+fn pack<T>(ptr: *mut T, tag: u16) -> u64 {
+    let addr = ptr.expose_provenance() as u64;
+    debug_assert_eq!(addr & !ADDR_MASK, 0, "pointer uses the high 16 bits");
+    ((tag as u64) << TAG_SHIFT) | addr
+}
+
+fn unpack<T>(word: u64) -> (*mut T, u16) {
+    (std::ptr::with_exposed_provenance_mut((word & ADDR_MASK) as usize), (word >> TAG_SHIFT) as u16)
+}
+
+/// A pointer slot whose every successful CAS also bumps a 16-bit generation tag.
+///
+/// The marker is `AtomicPtr<T>`, not `*mut T`: like `AtomicPtr`, the slot only stores
+/// and compares addresses (it never dereferences or frees them), so it is `Send + Sync`
+/// for any `T` — sharing it between threads is the whole point.
+pub struct TaggedPtr<T> {
+    word: AtomicU64,
+    _marker: std::marker::PhantomData<AtomicPtr<T>>,
+}
+
+impl<T> TaggedPtr<T> {
+    pub fn new(ptr: *mut T) -> Self {
+        TaggedPtr { word: AtomicU64::new(pack(ptr, 0)), _marker: std::marker::PhantomData }
+    }
+
+    /// Snapshot: the packed word to CAS against later, plus its unpacked view.
+    pub fn load(&self) -> (u64, *mut T, u16) {
+        let word = self.word.load(Acquire);
+        let (ptr, tag) = unpack(word);
+        (word, ptr, tag)
+    }
+
+    /// Install `new` if the slot still holds exactly `expected` (pointer *and* tag).
+    pub fn compare_exchange(&self, expected: u64, new: *mut T) -> Result<u64, u64> {
+        let (_, tag) = unpack::<T>(expected);
+        let desired = pack(new, tag.wrapping_add(1));
+        self.word.compare_exchange(expected, desired, AcqRel, Acquire).map(|_| desired)
+    }
+}
+
+pub fn ex_tagged_pointer() {
+    println!("\n== 9) Tagged pointer: generation counter vs ABA ==");
+    assert_eq!(std::mem::size_of::<usize>(), 8, "packing assumes a 64-bit target");
+    let a = Box::into_raw(Box::new("A"));
+    let b = Box::into_raw(Box::new("B"));
+
+    // Round-trip sanity: pointer and tag come back unchanged.
+    assert_eq!(unpack::<&str>(pack(a, 0xBEEF)), (a, 0xBEEF));
+
+    let slot = TaggedPtr::new(a);
+    let plain = AtomicPtr::new(a); // untagged twin for comparison
+
+    // Thread 1 takes a snapshot…
+    let (stale, p, tag) = slot.load();
+    let plain_stale = plain.load(Acquire);
+    assert_eq!((p, tag), (a, 0));
+
+    // …meanwhile others go A → B → A. Each successful CAS bumps the tag.
+    let w1 = slot.compare_exchange(stale, b).unwrap();
+    assert_eq!(unpack::<&str>(w1), (b, 1));
+    let w2 = slot.compare_exchange(w1, a).unwrap();
+    assert_eq!(unpack::<&str>(w2), (a, 2));
+    plain.store(b, Release);
+    plain.store(a, Release);
+
+    // Thread 1 resumes. The raw pointer matches its snapshot again:
+    let (_, now_ptr, now_tag) = slot.load();
+    assert_eq!(now_ptr, p);
+    // …untagged CAS can't tell anything happened (ABA goes undetected):
+    assert!(plain.compare_exchange(plain_stale, b, AcqRel, Acquire).is_ok());
+    // …but the tagged CAS fails, since generation 0 != 2, and reports the current word.
+    assert_eq!(slot.compare_exchange(stale, b), Err(w2));
+    println!("stale (A, gen 0) rejected; slot holds (A, gen {now_tag})");
+
+    // Retrying from a fresh snapshot succeeds and bumps the tag again.
+    let (fresh, _, _) = slot.load();
+    let w3 = slot.compare_exchange(fresh, b).unwrap();
+    assert_eq!(unpack::<&str>(w3), (b, 3));
+
+    // Shared between threads: two threads each re-install whatever pointer they load,
+    // 30_000 times, in a CAS retry loop (a barrier lines up their starts so they really
+    // race). Every success bumps the tag exactly once, so the final tag counts all
+    // successes — none lost to a racing update. 2 × 30_000 stays below the u16 wrap.
+    fn assert_send_sync<S: Send + Sync>() {}
+    assert_send_sync::<TaggedPtr<&str>>();
+    const PER_THREAD: u16 = 30_000;
+    let shared = TaggedPtr::new(a);
+    let start = std::sync::Barrier::new(2);
+    let retries: u32 = thread::scope(|s| {
+        let workers: Vec<_> = (0..2)
+            .map(|_| {
+                s.spawn(|| {
+                    let mut retries = 0;
+                    start.wait();
+                    for _ in 0..PER_THREAD {
+                        let (mut cur, mut p, _) = shared.load();
+                        while let Err(actual) = shared.compare_exchange(cur, p) {
+                            retries += 1; // the other thread won this round: retry from its word
+                            (cur, (p, _)) = (actual, unpack(actual));
+                        }
+                    }
+                    retries
+                })
+            })
+            .collect();
+        workers.into_iter().map(|w| w.join().unwrap()).sum()
+    });
+    let (_, p, tag) = shared.load();
+    println!("two threads: {} successful CASes, {retries} retries", tag);
+    assert_eq!((p, tag), (a, 2 * PER_THREAD));
+
+    // Tag wraps around at u16::MAX.
+    let wrap = TaggedPtr { word: AtomicU64::new(pack(a, u16::MAX)), _marker: std::marker::PhantomData };
+    let (w, _, _) = wrap.load();
+    assert_eq!(unpack::<&str>(wrap.compare_exchange(w, a).unwrap()).1, 0);
+
+    // SAFETY: both boxes were leaked above and are freed exactly once here.
+    unsafe { drop((Box::from_raw(a), Box::from_raw(b))) };
+}
+
+/* ─────────────── 10) Lazy<T>: double-checked locking ───────────────
+Check 1 (fast path): `ready.load(Acquire)`; if true the value is published, read it, no lock.
+Check 2 (slow path): take the Mutex, look at `ready` *again* — another thread may have
+initialized while we waited — and only then run the initializer, write the value and
+`ready.store(true, Release)`. The Release/Acquire pair is what makes check 1 correct: a
+reader that sees `true` also sees the value written before it. (The classic broken version
+uses a plain bool / Relaxed, so a reader can see the flag before the value.)
+std's `Once` / `OnceLock` / `LazyLock` implement the same contract (with a futex-style
+queue instead of a Mutex) — prefer them in real code; this is the mechanism spelled out.
+If the initializer panics, the Mutex is poisoned and later `get`s panic too.
+*/
+pub struct Lazy<T, F = fn() -> T> {
+    ready: AtomicBool,
+    init: Mutex<Option<F>>, // slow path lock; also holds the initializer until it runs
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+// SAFETY: `value` is written once, under the lock, before `ready` is released; after that
+// it is only read through `&T`. So sharing needs T: Sync (readers) + Send (built on one
+// thread, dropped on another), and F: Send (it may run on any thread).
+unsafe impl<T: Send + Sync, F: Send> Sync for Lazy<T, F> {}
+
+impl<T, F: FnOnce() -> T> Lazy<T, F> {
+    pub const fn new(init: F) -> Self {
+        Lazy { ready: AtomicBool::new(false), init: Mutex::new(Some(init)), value: UnsafeCell::new(MaybeUninit::uninit()) }
+    }
+
+    pub fn get(&self) -> &T {
+        if self.ready.load(Acquire) {
+            // SAFETY: `ready` is only set after `value` was written (Release/Acquire).
+            return unsafe { (*self.value.get()).assume_init_ref() };
+        }
+        self.init_slow()
+    }
+
+    #[cold]
+    fn init_slow(&self) -> &T {
+        let mut init = self.init.lock().unwrap();
+        if !self.ready.load(Relaxed) {
+            // Relaxed is enough here: the Mutex already orders us after the initializer.
+            let f = init.take().expect("initializer already taken");
+            // SAFETY: we hold the lock and `ready` is false → nobody reads `value` yet.
+            unsafe { (*self.value.get()).write(f()) };
+            self.ready.store(true, Release);
+        }
+        drop(init);
+        // SAFETY: `ready` is true (set by us, or by a thread that held the lock before us).
+        unsafe { (*self.value.get()).assume_init_ref() }
+    }
+}
+
+impl<T, F> Drop for Lazy<T, F> {
+    fn drop(&mut self) {
+        if *self.ready.get_mut() {
+            // SAFETY: initialized, and `&mut self` means no outstanding `&T`.
+            unsafe { self.value.get_mut().assume_init_drop() };
+        }
+    }
+}
+
+pub fn ex_lazy_double_checked() {
+    println!("\n== 10) Lazy<T>: double-checked locking vs std::sync::Once ==");
+    static INIT_RUNS: AtomicUsize = AtomicUsize::new(0);
+    static CONFIG: Lazy<Vec<u64>> = Lazy::new(|| {
+        INIT_RUNS.fetch_add(1, Relaxed);
+        thread::sleep(Duration::from_millis(10)); // widen the race window
+        (1..=5).collect()
+    });
+
+    const THREADS: usize = 16;
+    let start = Arc::new(std::sync::Barrier::new(THREADS));
+    let handles: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let start = start.clone();
+            thread::spawn(move || {
+                start.wait(); // everyone hits `get()` at once
+                let v = CONFIG.get();
+                (v as *const Vec<u64> as usize, v.iter().sum::<u64>())
+            })
+        })
+        .collect();
+    let seen: Vec<(usize, u64)> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+
+    assert_eq!(INIT_RUNS.load(Relaxed), 1); // exactly one initializer ran
+    assert!(seen.iter().all(|&s| s == seen[0])); // same object, same contents everywhere
+    assert_eq!(seen[0].1, 15);
+    println!("{THREADS} racing threads → initializer ran {} time(s), all saw sum = {}", INIT_RUNS.load(Relaxed), seen[0].1);
+
+    // Same guarantee from std: `Once::call_once` runs its closure exactly once.
+    static ONCE: std::sync::Once = std::sync::Once::new();
+    static ONCE_RUNS: AtomicUsize = AtomicUsize::new(0);
+    let hs: Vec<_> = (0..THREADS).map(|_| thread::spawn(|| ONCE.call_once(|| { ONCE_RUNS.fetch_add(1, Relaxed); }))).collect();
+    for h in hs { h.join().unwrap(); }
+    assert_eq!(ONCE_RUNS.load(Relaxed), 1);
+
+    // Non-static use: the value is dropped with the Lazy (only if it was initialized).
+    let local = Lazy::new(|| String::from("built on demand"));
+    assert_eq!(local.get(), "built on demand");
+    let never: Lazy<String, _> = Lazy::new(|| unreachable!("never forced"));
+    drop(never); // no init, no drop of uninit memory
+}
+
+/* ─────────────── 11) fetch_max / fetch_min by hand (CAS loop) ───────────────
+std already has `AtomicU64::fetch_max/fetch_min` (one `lock cmpxchg` loop on x86, a single
+`ldumax`/`ldumin` on ARMv8.1+). The hand-written version is the general recipe for any
+"read, compute, write if unchanged" update that has no dedicated instruction:
+- load the current value, stop early if the update wouldn't change it,
+- otherwise `compare_exchange_weak(cur, new)`; on failure we get the fresh value, retry.
+Returns the previous value, like std. The early exit is a plain load, so it gives only
+`Relaxed`-strength ordering when no write happens — fine for statistics; if you need
+Acquire on every call, use std's `fetch_max` (it always performs an RMW).
+*/
+pub trait AtomicMinMax {
+    fn atomic_fetch_max(&self, val: u64, order: Ordering) -> u64;
+    fn atomic_fetch_min(&self, val: u64, order: Ordering) -> u64;
+}
+
+impl AtomicMinMax for AtomicU64 {
+    fn atomic_fetch_max(&self, val: u64, order: Ordering) -> u64 {
+        let mut cur = self.load(Relaxed);
+        while cur < val {
+            match self.compare_exchange_weak(cur, val, order, Relaxed) {
+                Ok(prev) => return prev,
+                Err(now) => cur = now, // someone else wrote; re-check against their value
+            }
+        }
+        cur
+    }
+
+    fn atomic_fetch_min(&self, val: u64, order: Ordering) -> u64 {
+        let mut cur = self.load(Relaxed);
+        while cur > val {
+            match self.compare_exchange_weak(cur, val, order, Relaxed) {
+                Ok(prev) => return prev,
+                Err(now) => cur = now,
+            }
+        }
+        cur
+    }
+}
+
+pub fn ex_fetch_max_min() {
+    println!("\n== 11) atomic_fetch_max / atomic_fetch_min via CAS loops ==");
+    // Returns the previous value; only moves in one direction.
+    let hi = AtomicU64::new(10);
+    assert_eq!(hi.atomic_fetch_max(7, Relaxed), 10); // no change
+    assert_eq!(hi.atomic_fetch_max(42, Relaxed), 10);
+    assert_eq!(hi.load(Relaxed), 42);
+    let lo = AtomicU64::new(10);
+    assert_eq!(lo.atomic_fetch_min(12, Relaxed), 10);
+    assert_eq!(lo.atomic_fetch_min(3, Relaxed), 10);
+    assert_eq!(lo.load(Relaxed), 3);
+
+    // Threads record "latencies" (xorshift, deterministic per thread) into a shared max/min;
+    // std's fetch_max/fetch_min run alongside as the reference.
+    const THREADS: u64 = 8;
+    const SAMPLES: usize = 20_000;
+    let stats = Arc::new([AtomicU64::new(0), AtomicU64::new(u64::MAX), AtomicU64::new(0), AtomicU64::new(u64::MAX)]);
+    let handles: Vec<_> = (0..THREADS)
+        .map(|t| {
+            let stats = stats.clone();
+            thread::spawn(move || {
+                let mut x = 0x9E37_79B9_7F4A_7C15 ^ (t + 1);
+                let mut seen = Vec::with_capacity(SAMPLES);
+                for _ in 0..SAMPLES {
+                    x ^= x << 13;
+                    x ^= x >> 7;
+                    x ^= x << 17;
+                    let v = x % 1_000_000;
+                    stats[0].atomic_fetch_max(v, Relaxed);
+                    stats[1].atomic_fetch_min(v, Relaxed);
+                    stats[2].fetch_max(v, Relaxed);
+                    stats[3].fetch_min(v, Relaxed);
+                    seen.push(v);
+                }
+                seen
+            })
+        })
+        .collect();
+    let all: Vec<u64> = handles.into_iter().flat_map(|h| h.join().unwrap()).collect();
+
+    let (max, min) = (*all.iter().max().unwrap(), *all.iter().min().unwrap());
+    let [m, n, std_m, std_n] = [0, 1, 2, 3].map(|i| stats[i].load(Relaxed));
+    println!("{} samples: max = {m} (std {std_m}), min = {n} (std {std_n})", all.len());
+    assert_eq!((m, n), (max, min)); // no lost update despite contention
+    assert_eq!((m, n), (std_m, std_n));
+}
+
+/* ─────────────── 12) SeqGen: unique sequence numbers, with wraparound detection ───────────────
+Handing out IDs only needs *atomicity*, not ordering: `fetch_add(1, Relaxed)` can never give
+two threads the same value, because the RMW on one location is totally ordered. Relaxed is
+enough as long as the number itself is the only thing being published.
+`fetch_add` wraps silently at `u64::MAX` (then repeats 0, 1, …). At a billion IDs per second
+that takes ~584 years, but counters seeded from elsewhere (a persisted high-water mark, a
+narrower type) get there sooner. `next_checked` is the CAS-loop variant: it refuses to
+advance past `u64::MAX`, so the generator becomes permanently exhausted instead of reusing IDs.
+*/
+pub struct SeqGen {
+    counter: AtomicU64,
+}
+
+impl SeqGen {
+    pub fn new() -> Self {
+        Self::starting_at(0)
+    }
+
+    pub fn starting_at(first: u64) -> Self {
+        SeqGen { counter: AtomicU64::new(first) }
+    }
+
+    /// Next number; wraps around to 0 after `u64::MAX`.
+    pub fn next(&self) -> u64 {
+        self.counter.fetch_add(1, Relaxed)
+    }
+
+    /// Next number, or `None` once handing one out would wrap the counter.
+    pub fn next_checked(&self) -> Option<u64> {
+        let mut cur = self.counter.load(Relaxed);
+        loop {
+            let bumped = cur.checked_add(1)?; // counter stays at MAX: exhausted for good
+            match self.counter.compare_exchange_weak(cur, bumped, Relaxed, Relaxed) {
+                Ok(_) => return Some(cur),
+                Err(now) => cur = now,
+            }
+        }
+    }
+}
+
+impl Default for SeqGen {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn ex_seq_gen() {
+    use std::collections::HashSet;
+    println!("\n== 12) SeqGen: fetch_add IDs + checked CAS variant ==");
+
+    // Concurrent: every number handed out is unique, whichever method each thread uses.
+    const THREADS: usize = 8;
+    const PER_THREAD: usize = 10_000;
+    let ids = Arc::new(SeqGen::new());
+    let handles: Vec<_> = (0..THREADS)
+        .map(|t| {
+            let ids = ids.clone();
+            thread::spawn(move || {
+                (0..PER_THREAD)
+                    .map(|_| if t % 2 == 0 { ids.next() } else { ids.next_checked().unwrap() })
+                    .collect::<Vec<u64>>()
+            })
+        })
+        .collect();
+    let all: Vec<u64> = handles.into_iter().flat_map(|h| h.join().unwrap()).collect();
+    let unique: HashSet<u64> = all.iter().copied().collect();
+    println!("{} ids from {THREADS} threads, {} unique", all.len(), unique.len());
+    assert_eq!(unique.len(), THREADS * PER_THREAD);
+    assert_eq!(unique.iter().max().copied(), Some((THREADS * PER_THREAD) as u64 - 1)); // dense 0..N
+
+    // Near the top: checked stops, and stays stopped.
+    let near = SeqGen::starting_at(u64::MAX - 2);
+    assert_eq!(near.next_checked(), Some(u64::MAX - 2));
+    assert_eq!(near.next_checked(), Some(u64::MAX - 1));
+    assert_eq!(near.next_checked(), None);
+    assert_eq!(near.next_checked(), None);
+    println!("checked from MAX-2: MAX-2, MAX-1, then None");
+
+    // Unchecked wraps and starts repeating.
+    let wrap = SeqGen::starting_at(u64::MAX);
+    assert_eq!(wrap.next(), u64::MAX);
+    assert_eq!(wrap.next(), 0); // an ID that was (in principle) already issued
+}
+
+/* ─────────────── 13) AtomicReservoir<K>: lock-free reservoir sampling ───────────────
+Reservoir sampling keeps a uniform random sample of K items from a stream of unknown
+length: item #i (0-based) goes straight into slot i while i < K; after that it replaces a
+random slot with probability K/(i+1). Here the counter is one `fetch_add` (every offer gets
+a unique index) and each slot is an `AtomicU64` written with a plain store, so `offer`
+never blocks. The price is approximate fairness: two offers racing for the same slot
+both "win" and the later store silently discards the earlier one. For monitoring/stats
+samples that is usually fine; exact sampling needs a lock (or per-thread reservoirs merged
+at the end). Randomness comes from a thread-local xorshift seeded per thread, so threads
+don't contend on a shared RNG either.
+*/
+pub struct AtomicReservoir<const K: usize> {
+    slots: [AtomicU64; K],
+    seen: AtomicU64,
+}
+
+impl<const K: usize> AtomicReservoir<K> {
+    pub fn new() -> Self {
+        AtomicReservoir { slots: std::array::from_fn(|_| AtomicU64::new(0)), seen: AtomicU64::new(0) }
+    }
+
+    pub fn offer(&self, value: u64) {
+        let i = self.seen.fetch_add(1, Relaxed);
+        let slot = if i < K as u64 { i } else { thread_rng_u64() % (i + 1) };
+        if slot < K as u64 {
+            self.slots[slot as usize].store(value, Relaxed);
+        }
+    }
+
+    /// Total offers so far.
+    pub fn seen(&self) -> u64 {
+        self.seen.load(Relaxed)
+    }
+
+    /// The current sample (`min(seen, K)` values). Meant for quiescent moments (after the
+    /// producers are joined): while offers are in flight a just-claimed slot may still be 0.
+    pub fn snapshot(&self) -> Vec<u64> {
+        let filled = self.seen().min(K as u64) as usize;
+        self.slots[..filled].iter().map(|s| s.load(Relaxed)).collect()
+    }
+}
+
+impl<const K: usize> Default for AtomicReservoir<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn thread_rng_u64() -> u64 {
+    use std::cell::Cell;
+    use std::hash::{BuildHasher, RandomState};
+    thread_local! {
+        // Random per-thread seed; `| 1` keeps xorshift out of its all-zero fixed point.
+        static STATE: Cell<u64> = Cell::new(RandomState::new().hash_one(thread::current().id()) | 1);
+    }
+    STATE.with(|s| {
+        let mut x = s.get();
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        s.set(x);
+        x
+    })
+}
+
+pub fn ex_atomic_reservoir() {
+    use std::collections::HashSet;
+    println!("\n== 13) AtomicReservoir<K>: lock-free reservoir sampling ==");
+
+    // Fewer offers than slots: the sample is exactly what was offered.
+    let small = AtomicReservoir::<8>::new();
+    for v in [10, 20, 30] {
+        small.offer(v);
+    }
+    assert_eq!(small.snapshot(), [10, 20, 30]);
+
+    // Many producers, each offering a disjoint range of ids.
+    const THREADS: u64 = 8;
+    const PER_THREAD: u64 = 20_000;
+    let res = Arc::new(AtomicReservoir::<64>::new());
+    let handles: Vec<_> = (0..THREADS)
+        .map(|t| {
+            let res = Arc::clone(&res);
+            thread::spawn(move || {
+                for v in t * PER_THREAD + 1..=(t + 1) * PER_THREAD {
+                    res.offer(v);
+                }
+            })
+        })
+        .collect();
+    for h in handles {
+        h.join().unwrap();
+    }
+
+    let total = THREADS * PER_THREAD;
+    let sample = res.snapshot();
+    let mean = sample.iter().sum::<u64>() as f64 / sample.len() as f64;
+    println!("seen {} values, sample of {} (mean {mean:.0}, stream mean {})", res.seen(), sample.len(), total / 2);
+    assert_eq!(res.seen(), total);
+    assert_eq!(sample.len(), 64); // full
+    assert!(sample.iter().all(|v| (1..=total).contains(v))); // only values that were offered
+    assert_eq!(sample.iter().collect::<HashSet<_>>().len(), 64); // each offered once → no repeats
+    // A uniform sample of 64 has mean ≈ total/2 (σ ≈ 3.6% of total); a reservoir that kept
+    // only early or only late values would be far off.
+    assert!((mean - total as f64 / 2.0).abs() < total as f64 * 0.25);
+}
+
 /* ───────────────────────────── Docs-style notes ─────────────────────────────
 
 STANDARD ATOMICS
@@ -236,6 +1045,11 @@ ATOMICPTR
 - Use Release store to publish a fully-initialized object; readers use Acquire load.
 - Manage ownership carefully (who frees the allocation?).
 
+REFCOUNTING (MiniArc / std Arc)
+- Increment: Relaxed (holding a ref already keeps the object alive).
+- Decrement: Release; last owner adds `fence(Acquire)` before freeing.
+- Guard against count overflow (std aborts past isize::MAX).
+
 ATOMICCELL<T> (crossbeam)
 - Works for any `T: Copy` (+ a few special cases). API: new, load, store, swap,
   fetch_update, take, into_inner, etc. Some versions include numeric fetch_add/sub.
@@ -248,7 +1062,8 @@ PITFALLS
   thread might see the flag but not the data.
 - **Holding references**: Don’t read a pointer atomically and then use it after another
   thread might have freed it. Pair atomics with ownership protocols (hazard pointers,
-  epochs, RCU) or make sure only one party frees.
+  epochs, RCU) or make sure only one party frees. See `EpochStack` (section 7):
+  unlinked nodes go to `guard.defer_destroy` and are freed once no pinned thread remains.
 - **ABA problem**: CAS can be fooled if a value changes A→B→A. Use tagged pointers or
   sequence counters when necessary.
 - **Spin without backoff**: use `std::hint::spin_loop()` in tight CAS loops, or prefer channels/locks when appropriate.
@@ -263,6 +1078,7 @@ CHEAT SHEET
 - Observe published data:    `while !flag.load(Acquire) {}`; then read `data`
 - One-time init (CAS):       `cas(0, new, AcqRel, Acquire)`
 - AtomicCell number bump:    `cell.fetch_update(Relaxed, Relaxed, |x| Some(x+1))`
+- Running max (CAS loop):    `while cur < v { match cas_weak(cur, v) { Ok(p) => return p, Err(c) => cur = c } }`
 - Pointer publish:           `fence(Release); AP.store(ptr, Release)`
 
 */ 
@@ -3,8 +3,16 @@ use atomic_docs::{
     ex_atomic_cell_basics,
     ex_atomic_cell_threads,
     ex_atomic_ptr_and_fence,
+    ex_atomic_reservoir,
     ex_compare_exchange,
+    ex_epoch_reclaim,
+    ex_fetch_max_min,
+    ex_lazy_double_checked,
+    ex_mini_arc,
     ex_relaxed_counter,
+    ex_seq_gen,
+    ex_sharded_counter,
+    ex_tagged_pointer,
 };
 
 fn main() {
@@ -14,6 +22,14 @@ fn main() {
     ex_atomic_ptr_and_fence();
     ex_atomic_cell_basics();
     ex_atomic_cell_threads();
+    ex_mini_arc();
+    ex_epoch_reclaim();
+    ex_sharded_counter();
+    ex_tagged_pointer();
+    ex_lazy_double_checked();
+    ex_fetch_max_min();
+    ex_seq_gen();
+    ex_atomic_reservoir();
 
     println!("\n== Cheatsheet (see comments below) ==");
 }
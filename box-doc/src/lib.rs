@@ -1,5 +1,6 @@
 //! Simple docs + examples for Box<T>
 
+use std::collections::TryReserveError;
 use std::fmt::Debug;
 
 //
@@ -65,6 +66,740 @@ pub fn example_borrow() {
     println!("again   = {}", b);
 }
 
+//
+// Example 5: One-shot callbacks with Box<dyn FnOnce>
+//
+// `FnOnce::call_once(self, ..)` takes the closure *by value* — calling it may
+// move captured values out, so afterwards there is nothing left to call.
+// That is why `&Box<dyn FnOnce()>` (or `vec.iter()`) can't call it: a shared
+// reference can't give up ownership. Move the box out first, then call.
+//
+pub fn example_boxed_fnonce() {
+    let greeting = String::from("hello");
+    let name = String::from("box");
+
+    let callbacks: Vec<Box<dyn FnOnce() -> String>> = vec![
+        Box::new(move || greeting + " world"), // consumes `greeting`
+        Box::new(move || name.to_uppercase()),
+        Box::new(|| String::from("static")),
+    ];
+
+    // for f in callbacks.iter() { f(); } // error: cannot move out of `*f`
+
+    let mut results = Vec::new();
+    for f in callbacks { // `callbacks` moved into the loop: each box owned once
+        let s = f();     // Box<dyn FnOnce> is callable directly (since 1.35)
+        println!("callback -> {}", s);
+        results.push(s);
+    }
+    // println!("{}", callbacks.len()); // error: borrow of moved value
+
+    assert_eq!(results, ["hello world", "BOX", "static"]);
+}
+
+//
+// Example 6: Deep Box trees — iterate instead of recursing
+//
+// Recursive Box structures can be arbitrarily deep, but the call stack is not.
+// A naive recursive `eval` (and even the compiler-generated Drop!) uses one stack
+// frame per level, so a 100_000-deep chain overflows the stack. Use an explicit
+// heap-allocated stack (`Vec`) for both evaluation and dropping.
+//
+pub enum Expr {
+    Num(i64),
+    Add(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+}
+
+enum Step<'a> {
+    Visit(&'a Expr), // evaluate this node's children, then combine
+    Add,             // pop two values, push their sum
+    Mul,             // pop two values, push their product
+}
+
+impl Expr {
+    pub fn eval(&self) -> i64 {
+        let mut todo = vec![Step::Visit(self)];
+        let mut values: Vec<i64> = Vec::new();
+        while let Some(step) = todo.pop() {
+            match step {
+                Step::Visit(Expr::Num(n)) => values.push(*n),
+                Step::Visit(Expr::Add(l, r)) => todo.extend([Step::Add, Step::Visit(r), Step::Visit(l)]),
+                Step::Visit(Expr::Mul(l, r)) => todo.extend([Step::Mul, Step::Visit(r), Step::Visit(l)]),
+                Step::Add | Step::Mul => {
+                    let (r, l) = (values.pop().unwrap(), values.pop().unwrap());
+                    values.push(if matches!(step, Step::Add) { l.wrapping_add(r) } else { l.wrapping_mul(r) });
+                }
+            }
+        }
+        values.pop().unwrap()
+    }
+
+    // Reference implementation: clear, but one stack frame per tree level.
+    fn eval_recursive(&self) -> i64 {
+        match self {
+            Expr::Num(n) => *n,
+            Expr::Add(l, r) => l.eval_recursive().wrapping_add(r.eval_recursive()),
+            Expr::Mul(l, r) => l.eval_recursive().wrapping_mul(r.eval_recursive()),
+        }
+    }
+
+    // Move both children out (leaving cheap leaves behind) so they can be dropped later.
+    fn take_children(&mut self, out: &mut Vec<Expr>) {
+        if let Expr::Add(l, r) | Expr::Mul(l, r) = self {
+            out.push(std::mem::replace(&mut **l, Expr::Num(0)));
+            out.push(std::mem::replace(&mut **r, Expr::Num(0)));
+        }
+    }
+}
+
+impl Drop for Expr {
+    fn drop(&mut self) {
+        // Default drop glue would recurse Box -> Expr -> Box ... ; flatten it instead.
+        let mut pending = Vec::new();
+        self.take_children(&mut pending);
+        while let Some(mut node) = pending.pop() {
+            node.take_children(&mut pending);
+            // `node` now only owns leaves, so dropping it here is shallow.
+        }
+    }
+}
+
+pub fn example_deep_expr_iterative() {
+    fn num(n: i64) -> Box<Expr> { Box::new(Expr::Num(n)) }
+
+    // (2 + 3) * (4 + 1) = 25, small enough for the recursive reference.
+    let shallow = Expr::Mul(Box::new(Expr::Add(num(2), num(3))), Box::new(Expr::Add(num(4), num(1))));
+    println!("shallow: iterative = {}, recursive = {}", shallow.eval(), shallow.eval_recursive());
+    assert_eq!(shallow.eval(), shallow.eval_recursive());
+    assert_eq!(shallow.eval(), 25);
+
+    let mixed = Expr::Add(num(-7), Box::new(Expr::Mul(num(6), Box::new(Expr::Add(num(1), num(1))))));
+    assert_eq!(mixed.eval(), mixed.eval_recursive()); // -7 + 6 * 2 = 5
+
+    // ((((0 + 1) + 1) + 1) ...) — 100_000 levels, leaning left.
+    let mut deep = Expr::Num(0);
+    for _ in 0..100_000 {
+        deep = Expr::Add(Box::new(deep), num(1));
+    }
+    // deep.eval_recursive() would overflow the (main thread's) stack here.
+    println!("deep (100_000 levels): iterative = {}", deep.eval());
+    assert_eq!(deep.eval(), 100_000);
+    drop(deep); // custom Drop: no recursion either
+}
+
+//
+// Example 7: Object pool handing out Box<T>
+//
+// Each `Box::new` is a heap allocation; for short-lived, frequently recreated
+// buffers the allocator churn can dominate. A pool keeps returned boxes on a
+// free list and hands the same heap slots out again. `make` is a boxed factory
+// closure, so the pool type doesn't depend on which closure built it.
+//
+pub struct BoxPool<T> {
+    free: Vec<Box<T>>,
+    make: Box<dyn Fn() -> T>,
+}
+
+impl<T> BoxPool<T> {
+    pub fn new(make: impl Fn() -> T + 'static) -> Self {
+        BoxPool { free: Vec::new(), make: Box::new(make) }
+    }
+
+    /// Reuse a recycled box if there is one, otherwise allocate a fresh one.
+    pub fn checkout(&mut self) -> Box<T> {
+        self.free.pop().unwrap_or_else(|| Box::new((self.make)()))
+    }
+
+    /// Return a box for reuse. The value is kept as-is: reset it before reuse if needed.
+    pub fn checkin(&mut self, item: Box<T>) {
+        self.free.push(item);
+    }
+
+    pub fn available(&self) -> usize {
+        self.free.len()
+    }
+}
+
+pub fn example_box_pool() {
+    let mut pool: BoxPool<Vec<u8>> = BoxPool::new(|| Vec::with_capacity(1024));
+
+    // Empty pool → constructs.
+    let mut a = pool.checkout();
+    let b = pool.checkout();
+    let (addr_a, addr_b) = (&*a as *const Vec<u8>, &*b as *const Vec<u8>);
+    assert_ne!(addr_a, addr_b);
+    a.extend_from_slice(b"scratch");
+
+    // Recycle `a` (cleared, but its 1024-byte buffer is kept) and check out again.
+    a.clear();
+    pool.checkin(a);
+    assert_eq!(pool.available(), 1);
+    let again = pool.checkout();
+    println!("box addr before = {:p}, after checkin/checkout = {:p}", addr_a, &*again);
+    assert_eq!(&*again as *const Vec<u8>, addr_a); // same heap slot reused
+    assert!(again.capacity() >= 1024);            // inner buffer reused too
+
+    // Pool empty again → next checkout constructs a new box.
+    assert_eq!(pool.available(), 0);
+    let fresh = pool.checkout();
+    assert!(fresh.is_empty());
+    pool.checkin(again);
+    pool.checkin(b);
+    pool.checkin(fresh);
+    println!("boxes parked in pool = {}", pool.available());
+}
+
+//
+// Example 8: Visitor pattern over the boxed Expr tree (double dispatch)
+//
+// `accept` dispatches on the *node* (a match on the Expr variant), then calls a
+// method on `&mut dyn Visitor`, which dispatches on the *visitor* through its vtable.
+// New operations become new Visitor impls; Expr itself doesn't change. `visit_op`
+// gets the children and decides whether/when to recurse into them.
+// (Plain recursion: fine for normal trees, but see Example 6 for very deep ones.)
+//
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BinOp {
+    Add,
+    Mul,
+}
+
+pub trait Visitor {
+    fn visit_num(&mut self, n: i64);
+    fn visit_op(&mut self, op: BinOp, left: &Expr, right: &Expr);
+}
+
+impl Expr {
+    pub fn accept(&self, v: &mut dyn Visitor) {
+        match self {
+            Expr::Num(n) => v.visit_num(*n),
+            Expr::Add(l, r) => v.visit_op(BinOp::Add, l, r), // &Box<Expr> derefs to &Expr
+            Expr::Mul(l, r) => v.visit_op(BinOp::Mul, l, r),
+        }
+    }
+}
+
+/// Renders a fully parenthesized infix string.
+#[derive(Default)]
+pub struct PrintVisitor {
+    pub out: String,
+}
+
+impl Visitor for PrintVisitor {
+    fn visit_num(&mut self, n: i64) {
+        self.out += &n.to_string();
+    }
+    fn visit_op(&mut self, op: BinOp, left: &Expr, right: &Expr) {
+        self.out.push('(');
+        left.accept(self); // &mut PrintVisitor coerces to &mut dyn Visitor
+        self.out += if op == BinOp::Add { " + " } else { " * " };
+        right.accept(self);
+        self.out.push(')');
+    }
+}
+
+/// Post-order evaluation with a value stack: children push, the operator pops two.
+#[derive(Default)]
+pub struct EvalVisitor {
+    stack: Vec<i64>,
+}
+
+impl EvalVisitor {
+    pub fn result(&self) -> Option<i64> {
+        self.stack.last().copied()
+    }
+}
+
+impl Visitor for EvalVisitor {
+    fn visit_num(&mut self, n: i64) {
+        self.stack.push(n);
+    }
+    fn visit_op(&mut self, op: BinOp, left: &Expr, right: &Expr) {
+        left.accept(self);
+        right.accept(self);
+        let (r, l) = (self.stack.pop().unwrap(), self.stack.pop().unwrap());
+        self.stack.push(match op {
+            BinOp::Add => l.wrapping_add(r),
+            BinOp::Mul => l.wrapping_mul(r),
+        });
+    }
+}
+
+pub fn example_expr_visitor() {
+    fn num(n: i64) -> Box<Expr> { Box::new(Expr::Num(n)) }
+
+    // (2 + 3) * (4 + -1)
+    let expr = Expr::Mul(Box::new(Expr::Add(num(2), num(3))), Box::new(Expr::Add(num(4), num(-1))));
+
+    let mut printer = PrintVisitor::default();
+    expr.accept(&mut printer);
+    let mut evaluator = EvalVisitor::default();
+    expr.accept(&mut evaluator);
+    println!("{} = {:?}", printer.out, evaluator.result());
+    assert_eq!(printer.out, "((2 + 3) * (4 + -1))");
+    assert_eq!(evaluator.result(), Some(expr.eval())); // same answer as direct evaluation
+    assert_eq!(evaluator.result(), Some(15));
+
+    // The same call site works for any visitor: the choice is made at runtime.
+    let visitors: Vec<Box<dyn Visitor>> = vec![Box::new(PrintVisitor::default()), Box::new(EvalVisitor::default())];
+    for mut v in visitors {
+        Expr::Num(7).accept(v.as_mut());
+    }
+
+    let leaf = Expr::Num(42);
+    let mut p = PrintVisitor::default();
+    leaf.accept(&mut p);
+    assert_eq!(p.out, "42");
+}
+
+//
+// Example 9: Fallible allocation instead of abort
+//
+// `Box::new` / `Vec::with_capacity` abort the process if the allocator says no.
+// `Box::try_new` returns `Result` instead, but is nightly-only (`allocator_api`):
+//     let b: Result<Box<[u8; 4096]>, AllocError> = Box::try_new([0; 4096]);
+// On stable, `Vec::try_reserve_exact` is the fallible entry point; once the memory
+// is reserved, `into_boxed_slice` turns it into a `Box<[T]>` without reallocating.
+//
+pub fn try_boxed_slice(len: usize) -> Result<Box<[u8]>, TryReserveError> {
+    let mut v: Vec<u8> = Vec::new();
+    v.try_reserve_exact(len)?; // the only step that can fail
+    v.resize(len, 0);          // fits in the reservation: no further allocation
+    Ok(v.into_boxed_slice())
+}
+
+pub fn example_try_new() {
+    // Reasonable request: succeeds.
+    let buf = try_boxed_slice(64 * 1024).expect("64 KiB should be available");
+    assert_eq!(buf.len(), 64 * 1024);
+    assert!(buf.iter().all(|&b| b == 0));
+    println!("allocated {} bytes", buf.len());
+
+    // Absurd requests come back as Err — no abort, the caller decides what to do.
+    for len in [usize::MAX, isize::MAX as usize] {
+        match try_boxed_slice(len) {
+            Ok(_) => unreachable!("nobody has {len} bytes to spare"),
+            Err(e) => println!("try_boxed_slice({len}) failed gracefully: {e}"),
+        }
+    }
+    let mut v: Vec<u64> = Vec::new();
+    assert!(v.try_reserve(usize::MAX).is_err()); // capacity overflow, not an abort
+    assert!(v.try_reserve(16).is_ok());
+
+    // A typical no-panic pattern: degrade instead of dying.
+    let size = try_boxed_slice(usize::MAX / 2).map(|b| b.len()).unwrap_or(0);
+    assert_eq!(size, 0);
+}
+
+//
+// Example 10: Parse + evaluate, with errors as Box<dyn Error>
+//
+// A recursive-descent parser builds the same `Box<Expr>` tree as above, then a
+// checked evaluation walks it — iteratively, like `eval` in Example 6, because a
+// long `1+1+…` chain is a deep left-leaning tree. Parsing only recurses on
+// parentheses, so their nesting is capped at `MAX_PAREN_DEPTH` to keep the parser
+// off the stack limit too. Three different error types can come out — our own
+// syntax/overflow errors and std's `ParseIntError` for an out-of-range literal —
+// and `?` boxes each of them into `Box<dyn Error>`, so the caller sees one type.
+//
+//   expr := term ('+' term)*
+//   term := atom ('*' atom)*
+//   atom := digits | '(' expr ')'
+//
+#[derive(Debug, PartialEq)]
+pub enum EvalError {
+    /// Byte offset and the character found there (`None` = end of input).
+    Unexpected { pos: usize, found: Option<char> },
+    Overflow,
+    /// Byte offset of the `(` that went past `MAX_PAREN_DEPTH`.
+    TooDeep { pos: usize },
+}
+
+const MAX_PAREN_DEPTH: usize = 256;
+
+impl std::fmt::Display for EvalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EvalError::Unexpected { pos, found: Some(c) } => write!(f, "unexpected {c:?} at {pos}"),
+            EvalError::Unexpected { pos, found: None } => write!(f, "unexpected end of input at {pos}"),
+            EvalError::Overflow => write!(f, "arithmetic overflow"),
+            EvalError::TooDeep { pos } => write!(f, "parentheses nested deeper than {MAX_PAREN_DEPTH} at {pos}"),
+        }
+    }
+}
+
+impl std::error::Error for EvalError {}
+
+pub fn eval_str(input: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    let mut p = Parser { src: input, pos: 0, depth: 0 };
+    let tree = p.expr()?;
+    if let Some(c) = p.peek() {
+        return Err(Box::new(EvalError::Unexpected { pos: p.pos, found: Some(c) })); // trailing junk
+    }
+    Ok(tree.eval_checked().ok_or(EvalError::Overflow)?)
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+    depth: usize, // currently open parentheses
+}
+
+impl Parser<'_> {
+    // Next non-space char, without consuming it.
+    fn peek(&mut self) -> Option<char> {
+        let rest = &self.src[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+        self.src[self.pos..].chars().next()
+    }
+
+    fn unexpected(&mut self) -> Box<dyn std::error::Error> {
+        let found = self.peek();
+        Box::new(EvalError::Unexpected { pos: self.pos, found })
+    }
+
+    fn expr(&mut self) -> Result<Box<Expr>, Box<dyn std::error::Error>> {
+        let mut lhs = self.term()?;
+        while self.peek() == Some('+') {
+            self.pos += 1;
+            lhs = Box::new(Expr::Add(lhs, self.term()?));
+        }
+        Ok(lhs)
+    }
+
+    fn term(&mut self) -> Result<Box<Expr>, Box<dyn std::error::Error>> {
+        let mut lhs = self.atom()?;
+        while self.peek() == Some('*') {
+            self.pos += 1;
+            lhs = Box::new(Expr::Mul(lhs, self.atom()?));
+        }
+        Ok(lhs)
+    }
+
+    fn atom(&mut self) -> Result<Box<Expr>, Box<dyn std::error::Error>> {
+        match self.peek() {
+            Some('(') => {
+                if self.depth == MAX_PAREN_DEPTH {
+                    return Err(Box::new(EvalError::TooDeep { pos: self.pos }));
+                }
+                self.pos += 1;
+                self.depth += 1;
+                let inner = self.expr()?;
+                if self.peek() != Some(')') {
+                    return Err(self.unexpected());
+                }
+                self.pos += 1;
+                self.depth -= 1;
+                Ok(inner)
+            }
+            Some(c) if c.is_ascii_digit() => {
+                let digits = self.src[self.pos..].bytes().take_while(u8::is_ascii_digit).count();
+                let n: i64 = self.src[self.pos..self.pos + digits].parse()?; // ParseIntError, boxed by `?`
+                self.pos += digits;
+                Ok(Box::new(Expr::Num(n)))
+            }
+            _ => Err(self.unexpected()),
+        }
+    }
+}
+
+impl Expr {
+    // Like `eval`, but `None` on overflow instead of wrapping.
+    fn eval_checked(&self) -> Option<i64> {
+        let mut todo = vec![Step::Visit(self)];
+        let mut values: Vec<i64> = Vec::new();
+        while let Some(step) = todo.pop() {
+            match step {
+                Step::Visit(Expr::Num(n)) => values.push(*n),
+                Step::Visit(Expr::Add(l, r)) => todo.extend([Step::Add, Step::Visit(r), Step::Visit(l)]),
+                Step::Visit(Expr::Mul(l, r)) => todo.extend([Step::Mul, Step::Visit(r), Step::Visit(l)]),
+                Step::Add | Step::Mul => {
+                    let (r, l) = (values.pop().unwrap(), values.pop().unwrap());
+                    values.push(if matches!(step, Step::Add) { l.checked_add(r)? } else { l.checked_mul(r)? });
+                }
+            }
+        }
+        values.pop()
+    }
+}
+
+pub fn example_eval_str() {
+    // Precedence and parentheses.
+    for (src, want) in [("1 + 2 * 3", 7), ("(1 + 2) * 3", 9), ("2*3*4 + 1", 25), (" 42 ", 42), ("((7))", 7)] {
+        let got = eval_str(src).unwrap();
+        println!("{src:>12} = {got}");
+        assert_eq!(got, want);
+    }
+
+    // Syntax errors: our own type inside the box; downcast to inspect it.
+    let err = eval_str("1 + * 2").unwrap_err();
+    println!("parse error: {err}");
+    assert_eq!(err.downcast_ref::<EvalError>(), Some(&EvalError::Unexpected { pos: 4, found: Some('*') }));
+    let err = eval_str("(1 + 2").unwrap_err();
+    assert_eq!(err.downcast_ref::<EvalError>(), Some(&EvalError::Unexpected { pos: 6, found: None }));
+    assert!(eval_str("").is_err());
+    assert!(eval_str("1 2").is_err()); // trailing input
+
+    // Overflow: checked arithmetic turns it into an error instead of wrapping.
+    let big = format!("{} + 1", i64::MAX);
+    let err = eval_str(&big).unwrap_err();
+    println!("overflow: {err}");
+    assert_eq!(err.downcast_ref::<EvalError>(), Some(&EvalError::Overflow));
+    assert!(eval_str("4294967296 * 4294967296").is_err()); // 2^32 * 2^32
+    // A literal too big for i64 is a *different* error type in the same box.
+    let err = eval_str("99999999999999999999").unwrap_err();
+    println!("bad literal: {err}");
+    assert!(err.is::<std::num::ParseIntError>());
+
+    // Long operator chains are deep trees, but evaluation doesn't recurse.
+    assert_eq!(eval_str(&vec!["1"; 200_000].join("+")).unwrap(), 200_000);
+    assert_eq!(eval_str(&vec!["1"; 200_000].join("*")).unwrap(), 1);
+    // Parentheses do recurse while parsing, so their depth is capped.
+    let nested = |n: usize| format!("{}1{}", "(".repeat(n), ")".repeat(n));
+    assert_eq!(eval_str(&nested(MAX_PAREN_DEPTH)).unwrap(), 1);
+    let err = eval_str(&nested(100_000)).unwrap_err();
+    println!("too deep: {err}");
+    assert_eq!(err.downcast_ref::<EvalError>(), Some(&EvalError::TooDeep { pos: MAX_PAREN_DEPTH }));
+}
+
+//
+// Example 11: Downcasting Box<dyn Trait> back to the concrete type (Any supertrait)
+//
+// A `Vec<Box<dyn Component>>` forgets the concrete types. `Any` remembers a `TypeId`
+// per type, so `downcast_ref::<T>()` can check at runtime and hand back `&T`. The
+// `as_any` method is the bridge from `&dyn Component` to `&dyn Any`; each impl is just
+// `self` (Rust 1.86+ can also upcast `&dyn Component as &dyn Any` directly).
+// `Any` requires `'static`, so components can't hold borrowed data.
+// This is the lookup at the heart of ECS-style registries and plugin systems.
+//
+pub trait Component: std::any::Any {
+    fn name(&self) -> &'static str;
+    fn as_any(&self) -> &dyn std::any::Any;
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Position {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Health(pub u32);
+
+impl Component for Position {
+    fn name(&self) -> &'static str { "position" }
+    fn as_any(&self) -> &dyn std::any::Any { self }
+}
+
+impl Component for Health {
+    fn name(&self) -> &'static str { "health" }
+    fn as_any(&self) -> &dyn std::any::Any { self }
+}
+
+#[derive(Default)]
+pub struct Registry {
+    components: Vec<Box<dyn Component>>,
+}
+
+impl Registry {
+    pub fn add(&mut self, c: impl Component) {
+        self.components.push(Box::new(c));
+    }
+
+    /// First registered component of type `T`, if any.
+    pub fn get<T: Component>(&self) -> Option<&T> {
+        // `c.as_any()` dispatches through the vtable to the concrete impl; calling
+        // `Any` methods on the `Box` itself would see the Box's type, not the content.
+        self.components.iter().find_map(|c| c.as_any().downcast_ref::<T>())
+    }
+
+    pub fn names(&self) -> Vec<&'static str> {
+        self.components.iter().map(|c| c.name()).collect()
+    }
+}
+
+pub fn example_any_registry() {
+    let mut reg = Registry::default();
+    reg.add(Position { x: 3, y: -1 });
+    reg.add(Health(100));
+    println!("registered: {:?}", reg.names());
+
+    let pos = reg.get::<Position>();
+    let hp = reg.get::<Health>();
+    println!("get::<Position>() = {:?}, get::<Health>() = {:?}", pos, hp);
+    assert_eq!(pos, Some(&Position { x: 3, y: -1 }));
+    assert_eq!(hp, Some(&Health(100)));
+
+    // A type that was never registered (here: declared locally) → None, not a panic.
+    struct Velocity;
+    impl Component for Velocity {
+        fn name(&self) -> &'static str { "velocity" }
+        fn as_any(&self) -> &dyn std::any::Any { self }
+    }
+    assert!(reg.get::<Velocity>().is_none());
+    assert!(Registry::default().get::<Health>().is_none());
+
+    // Once registered, the same lookup succeeds; existing entries are unaffected.
+    reg.add(Velocity);
+    assert!(reg.get::<Velocity>().is_some());
+    assert_eq!(reg.get::<Health>(), Some(&Health(100)));
+    assert_eq!(reg.names(), ["position", "health", "velocity"]);
+}
+
+//
+// Example 12: A recursive JSON-like value
+//
+// A type can contain itself only through indirection. `Vec<Json>` already is one (its
+// elements live on the heap), so `Arr` needs no Box. In `Obj` the Box keeps each entry at
+// `String` + one pointer instead of inlining a whole `Json` next to every key.
+// Like Example 6, anything that walks nesting depth uses a heap stack: `depth()` and
+// `Drop` are iterative, so a 10_000-level `[[[...]]]` is fine. `pretty()` recurses
+// (its output is as deep as the input anyway) and is meant for human-sized documents.
+//
+pub enum Json {
+    Null,
+    Bool(bool),
+    Num(f64),
+    Str(String),
+    Arr(Vec<Json>),
+    Obj(Vec<(String, Box<Json>)>),
+}
+
+impl Json {
+    /// Two-space indented JSON text. Non-finite numbers become `null`, as in JavaScript.
+    pub fn pretty(&self) -> String {
+        let mut out = String::new();
+        self.write_pretty(&mut out, 0);
+        out
+    }
+
+    fn write_pretty(&self, out: &mut String, indent: usize) {
+        let pad = |out: &mut String, n: usize| out.extend(std::iter::repeat_n("  ", n));
+        match self {
+            Json::Null => out.push_str("null"),
+            Json::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+            Json::Num(n) if !n.is_finite() => out.push_str("null"),
+            Json::Num(n) => out.push_str(&n.to_string()), // 1.0 prints as "1"
+            Json::Str(s) => write_json_str(out, s),
+            Json::Arr(items) if items.is_empty() => out.push_str("[]"),
+            Json::Obj(fields) if fields.is_empty() => out.push_str("{}"),
+            Json::Arr(items) => {
+                out.push_str("[\n");
+                for (i, item) in items.iter().enumerate() {
+                    pad(out, indent + 1);
+                    item.write_pretty(out, indent + 1);
+                    out.push_str(if i + 1 < items.len() { ",\n" } else { "\n" });
+                }
+                pad(out, indent);
+                out.push(']');
+            }
+            Json::Obj(fields) => {
+                out.push_str("{\n");
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    pad(out, indent + 1);
+                    write_json_str(out, key);
+                    out.push_str(": ");
+                    value.write_pretty(out, indent + 1);
+                    out.push_str(if i + 1 < fields.len() { ",\n" } else { "\n" });
+                }
+                pad(out, indent);
+                out.push('}');
+            }
+        }
+    }
+
+    /// Nesting depth: a scalar or an empty container is 1, a container is 1 + its deepest child.
+    pub fn depth(&self) -> usize {
+        let mut max = 0;
+        let mut todo = vec![(self, 1)];
+        while let Some((node, d)) = todo.pop() {
+            max = max.max(d);
+            match node {
+                Json::Arr(items) => todo.extend(items.iter().map(|c| (c, d + 1))),
+                Json::Obj(fields) => todo.extend(fields.iter().map(|(_, c)| (&**c, d + 1))),
+                _ => {}
+            }
+        }
+        max
+    }
+}
+
+fn write_json_str(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if u32::from(c) < 0x20 => out.push_str(&format!("\\u{:04x}", u32::from(c))),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+impl Drop for Json {
+    fn drop(&mut self) {
+        // Same trick as `Expr`: move children onto a Vec so no drop recurses.
+        let mut pending: Vec<Json> = Vec::new();
+        let take = |node: &mut Json, pending: &mut Vec<Json>| match node {
+            Json::Arr(items) => pending.append(items),
+            Json::Obj(fields) => pending.extend(fields.drain(..).map(|(_, v)| *v)),
+            _ => {}
+        };
+        take(self, &mut pending);
+        while let Some(mut node) = pending.pop() {
+            take(&mut node, &mut pending);
+        }
+    }
+}
+
+pub fn example_json_value() {
+    fn obj(fields: Vec<(&str, Json)>) -> Json {
+        Json::Obj(fields.into_iter().map(|(k, v)| (k.to_string(), Box::new(v))).collect())
+    }
+    let doc = obj(vec![
+        ("name", Json::Str("box \"doc\"".into())),
+        ("version", Json::Num(1.0)),
+        ("ratio", Json::Num(0.25)),
+        ("tags", Json::Arr(vec![Json::Str("heap".into()), Json::Bool(true), Json::Null])),
+        ("nested", obj(vec![("empty_arr", Json::Arr(vec![])), ("empty_obj", obj(vec![]))])),
+    ]);
+    let text = doc.pretty();
+    println!("{text}");
+    assert_eq!(
+        text,
+        r#"{
+  "name": "box \"doc\"",
+  "version": 1,
+  "ratio": 0.25,
+  "tags": [
+    "heap",
+    true,
+    null
+  ],
+  "nested": {
+    "empty_arr": [],
+    "empty_obj": {}
+  }
+}"#
+    );
+    assert_eq!(doc.depth(), 3); // doc → nested → empty_arr
+    assert_eq!(Json::Null.depth(), 1);
+    assert_eq!(Json::Num(f64::NAN).pretty(), "null");
+    assert_eq!(Json::Str("tab\there\n".into()).pretty(), r#""tab\u0009here\n""#);
+
+    // 10_000 levels of [[[...]]]: neither depth() nor drop touches the call stack per level.
+    let mut deep = Json::Arr(vec![]);
+    for _ in 1..10_000 {
+        deep = Json::Arr(vec![deep]);
+    }
+    println!("deep.depth() = {}", deep.depth());
+    assert_eq!(deep.depth(), 10_000);
+    drop(deep);
+}
+
 //
 // Docs-style comparison (for humans)
 //
@@ -77,6 +812,13 @@ pub fn example_borrow() {
 | Move on deref (`*b`)               | Moves (unless `Copy`), consumes box           |
 | Borrow (`&*b`, `as_ref`, `as_mut`) | Safe way to inspect/modify without moving     |
 | Thread safety                      | Same as `T` (box doesn’t add sync/atomic)     |
+| `Box<dyn FnOnce()>`                | Call by moving the box out (consumes it)      |
+| Deep recursive `Box` trees         | Walk/drop via explicit stack, not recursion   |
+| Pooling (`Vec<Box<T>>` free list)  | Reuse heap slots instead of re-allocating     |
+| Visitor (`&mut dyn Visitor`)       | New operations without changing the tree      |
+| Fallible (`try_reserve`)           | Err on OOM instead of aborting                |
+| `Box<dyn Error>`                   | One return type for many error types (`?`)    |
+| `Box<dyn Trait>` + `Any` supertrait| Downcast back to the concrete type at runtime |
 */
 
 //
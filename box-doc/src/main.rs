@@ -3,6 +3,14 @@ use box_doc::{
     example_recursive,
     example_trait_objects,
     example_borrow,
+    example_boxed_fnonce,
+    example_deep_expr_iterative,
+    example_box_pool,
+    example_expr_visitor,
+    example_try_new,
+    example_eval_str,
+    example_any_registry,
+    example_json_value,
 };
 
 fn main() {
@@ -17,4 +25,28 @@ fn main() {
 
     println!("\n--- Example 4: Borrow ---");
     example_borrow();
+
+    println!("\n--- Example 5: Boxed FnOnce callbacks ---");
+    example_boxed_fnonce();
+
+    println!("\n--- Example 6: Deep expression tree without recursion ---");
+    example_deep_expr_iterative();
+
+    println!("\n--- Example 7: Box object pool ---");
+    example_box_pool();
+
+    println!("\n--- Example 8: Visitor over a boxed tree ---");
+    example_expr_visitor();
+
+    println!("\n--- Example 9: Fallible allocation ---");
+    example_try_new();
+
+    println!("\n--- Example 10: Parsing with Box<dyn Error> ---");
+    example_eval_str();
+
+    println!("\n--- Example 11: Downcasting boxed trait objects ---");
+    example_any_registry();
+
+    println!("\n--- Example 12: Recursive JSON-like value ---");
+    example_json_value();
 }
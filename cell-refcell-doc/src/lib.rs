@@ -1,4 +1,6 @@
 use std::cell::{RefCell, Cell};
+use std::collections::HashMap;
+use std::rc::{Rc, Weak};
 
 struct CellCounter {
     count: Cell<u32>, // interior mutability
@@ -53,6 +55,201 @@ pub fn refcell_example() {
     println!("All  = {:?}", c.all());
 }
 
+struct FibMemo {
+    cache: RefCell<HashMap<u64, u64>>, // cache filled through &self
+    computed: Cell<u32>,               // how many subproblems were actually computed
+}
+
+impl FibMemo {
+    fn new() -> Self {
+        FibMemo { cache: RefCell::new(HashMap::new()), computed: Cell::new(0) }
+    }
+
+    fn fib(&self, n: u64) -> u64 {
+        // Copy the value out: the `Ref` guard is dropped at the end of this statement.
+        let cached = self.cache.borrow().get(&n).copied();
+        if let Some(v) = cached {
+            return v;
+        }
+        // No borrow is held here. Writing
+        //   let cache = self.cache.borrow_mut(); ... self.fib(n - 1) ...
+        // would panic with "already borrowed" as soon as the recursion touches the cache.
+        let v = if n < 2 { n } else { self.fib(n - 1) + self.fib(n - 2) };
+        self.computed.set(self.computed.get() + 1);
+        self.cache.borrow_mut().insert(n, v); // short mutable borrow, after recursion
+        v
+    }
+}
+
+pub fn refcell_memo_example() {
+    let memo = FibMemo::new(); // not `mut`: caching is interior mutability
+    let f50 = memo.fib(50);
+    println!("fib(50) = {}, subproblems computed = {}", f50, memo.computed.get());
+    assert_eq!(f50, 12_586_269_025);
+    assert_eq!(memo.computed.get(), 51); // fib(0..=50), each exactly once
+
+    // Everything up to 50 is cached now: no new computations.
+    assert_eq!((memo.fib(10), memo.fib(20)), (55, 6765));
+    assert_eq!(memo.computed.get(), 51);
+    assert_eq!(memo.fib(51), 20_365_011_074);
+    assert_eq!(memo.computed.get(), 52); // only fib(51) itself was new
+}
+
+struct GNode {
+    id: usize,
+    out: Vec<Weak<RefCell<GNode>>>, // Weak: edges must not keep nodes alive (cycles)
+    incoming: usize,
+}
+
+struct Graph {
+    nodes: Vec<Rc<RefCell<GNode>>>, // the graph owns the nodes
+}
+
+#[derive(Debug, PartialEq)]
+enum EdgeError {
+    NoSuchNode(usize),
+    Busy(usize), // node is currently borrowed elsewhere
+}
+
+impl Graph {
+    fn with_nodes(n: usize) -> Self {
+        let nodes = (0..n).map(|id| Rc::new(RefCell::new(GNode { id, out: Vec::new(), incoming: 0 }))).collect();
+        Graph { nodes }
+    }
+
+    fn node(&self, id: usize) -> Result<&Rc<RefCell<GNode>>, EdgeError> {
+        self.nodes.get(id).ok_or(EdgeError::NoSuchNode(id))
+    }
+
+    // Needs `&mut` on *both* endpoints at once. `borrow_mut` would panic if either is
+    // already borrowed — including `from == to`, where the second borrow conflicts with
+    // the first. `try_borrow_mut` turns that into an Err, and since both borrows are
+    // taken before anything is written, a failed call changes nothing.
+    fn add_edge(&self, from: usize, to: usize) -> Result<(), EdgeError> {
+        let (from_rc, to_rc) = (self.node(from)?, self.node(to)?);
+        let mut src = from_rc.try_borrow_mut().map_err(|_| EdgeError::Busy(from))?;
+        let mut dst = to_rc.try_borrow_mut().map_err(|_| EdgeError::Busy(to))?;
+        src.out.push(Rc::downgrade(to_rc));
+        dst.incoming += 1;
+        Ok(())
+    }
+
+    fn neighbours(&self, id: usize) -> Vec<usize> {
+        let node = self.nodes[id].borrow();
+        node.out.iter().filter_map(Weak::upgrade).map(|n| n.borrow().id).collect()
+    }
+}
+
+pub fn refcell_graph_example() {
+    let g = Graph::with_nodes(3);
+    assert_eq!(g.add_edge(0, 1), Ok(()));
+    assert_eq!(g.add_edge(0, 2), Ok(()));
+    assert_eq!(g.add_edge(1, 2), Ok(()));
+    println!("0 -> {:?}, 1 -> {:?}", g.neighbours(0), g.neighbours(1));
+    assert_eq!(g.neighbours(0), [1, 2]);
+    assert_eq!(g.nodes[2].borrow().incoming, 2);
+
+    // Self-edge: the node would have to be mutably borrowed twice at the same time.
+    let res = g.add_edge(1, 1);
+    println!("add_edge(1, 1) = {:?}", res);
+    assert_eq!(res, Err(EdgeError::Busy(1))); // Err, not a panic
+
+    // Someone else holds a borrow: rejected, and nothing was half-written.
+    {
+        let _reader = g.nodes[2].borrow();
+        assert_eq!(g.add_edge(0, 2), Err(EdgeError::Busy(2)));
+    }
+    assert_eq!(g.neighbours(0), [1, 2]); // the source's out-list was not touched
+    assert_eq!(g.add_edge(0, 2), Ok(())); // fine once the borrow is gone
+    assert_eq!(g.add_edge(0, 9), Err(EdgeError::NoSuchNode(9)));
+
+    // With plain borrow_mut the self-edge would unwind:
+    //   let a = n.borrow_mut(); let b = n.borrow_mut(); // panics: already mutably borrowed
+}
+
+// Undo/redo over snapshots, all through `&self` (an editor handing `&Document` to
+// several widgets). Each method keeps at most one borrow alive at a time: values are
+// moved between the cells with `RefCell::replace` / `take`, which borrow only for the
+// duration of the call, instead of holding a `RefMut` on one cell while touching another.
+pub struct Document {
+    text: RefCell<String>,
+    undo: RefCell<Vec<String>>, // previous versions, most recent last
+    redo: RefCell<Vec<String>>, // versions undone, most recent last
+}
+
+impl Document {
+    pub fn new(text: &str) -> Self {
+        Document { text: RefCell::new(text.to_string()), undo: RefCell::new(vec![]), redo: RefCell::new(vec![]) }
+    }
+
+    pub fn text(&self) -> String {
+        self.text.borrow().clone()
+    }
+
+    /// Apply `change` to the text. `change` gets the `&mut String` while the text is
+    /// mutably borrowed, so it must not call back into this document.
+    pub fn edit(&self, change: impl FnOnce(&mut String)) {
+        let before = self.text();                 // shared borrow, released here
+        change(&mut self.text.borrow_mut());      // RefMut lives for this statement only
+        self.undo.borrow_mut().push(before);
+        self.redo.borrow_mut().clear();           // a new edit forks history: no redo
+    }
+
+    /// Restore the previous version; `false` if there is nothing to undo.
+    pub fn undo(&self) -> bool {
+        let Some(prev) = self.undo.borrow_mut().pop() else { return false };
+        let current = self.text.replace(prev);
+        self.redo.borrow_mut().push(current);
+        true
+    }
+
+    /// Re-apply the most recently undone version; `false` if there is none.
+    pub fn redo(&self) -> bool {
+        let Some(next) = self.redo.borrow_mut().pop() else { return false };
+        let current = self.text.replace(next);
+        self.undo.borrow_mut().push(current);
+        true
+    }
+
+    pub fn history_len(&self) -> (usize, usize) {
+        (self.undo.borrow().len(), self.redo.borrow().len())
+    }
+}
+
+pub fn refcell_undo_redo_example() {
+    let doc = Document::new("Hello"); // no `mut` anywhere below
+    doc.edit(|t| t.push_str(", world"));
+    doc.edit(|t| t.push('!'));
+    doc.edit(|t| *t = t.to_uppercase());
+    println!("after edits: {:?}, (undo, redo) = {:?}", doc.text(), doc.history_len());
+    assert_eq!(doc.text(), "HELLO, WORLD!");
+    assert_eq!(doc.history_len(), (3, 0));
+
+    assert!(doc.undo());
+    assert_eq!(doc.text(), "Hello, world!");
+    assert!(doc.undo());
+    assert_eq!(doc.text(), "Hello, world");
+    assert!(doc.redo());
+    assert_eq!(doc.text(), "Hello, world!");
+    assert_eq!(doc.history_len(), (2, 1));
+
+    // A new edit after undo clears the redo stack: the undone "HELLO, WORLD!" is gone.
+    doc.edit(|t| t.insert_str(0, "> "));
+    assert_eq!(doc.text(), "> Hello, world!");
+    assert_eq!(doc.history_len(), (3, 0));
+    assert!(!doc.redo());
+    println!("after new edit: {:?}", doc.text());
+
+    // Undo all the way back, then one more is a no-op.
+    while doc.undo() {}
+    assert_eq!(doc.text(), "Hello");
+    assert!(!doc.undo());
+    assert_eq!(doc.history_len(), (0, 3));
+
+    // No borrow is left dangling between calls: every cell is free again.
+    assert!(doc.text.try_borrow_mut().is_ok() && doc.undo.try_borrow_mut().is_ok() && doc.redo.try_borrow_mut().is_ok());
+}
+
 /* 
 
 | `Cell<T>`                          | `RefCell<T>`                               |
@@ -93,5 +290,15 @@ Cell<T> = just UnsafeCell<T>, no borrow tracking.
 
 RefCell<T> = UnsafeCell<T> + a borrow counter.
 
+Memoization through &self (FibMemo):
+- RefCell<HashMap> lets a `&self` method fill a cache.
+- Never hold a Ref/RefMut across a call that may borrow the same cell again
+  (recursion, callbacks): copy the value out, drop the guard, then recurse.
+
+Fallible borrows (Graph::add_edge):
+- try_borrow()/try_borrow_mut() return Err instead of panicking when the cell is busy.
+- Take every borrow you need first, then mutate, so an Err leaves no half-done update.
+- Borrowing the same RefCell mutably twice (e.g. a self-edge) is such a conflict.
+
 Their runtime borrow-checking is not atomic → two threads could borrow at the same time, breaking safety.
 */
\ No newline at end of file
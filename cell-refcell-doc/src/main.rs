@@ -1,6 +1,11 @@
-use cell_refcell_doc::{cell_example, refcell_example};
+use cell_refcell_doc::{
+    cell_example, refcell_example, refcell_graph_example, refcell_memo_example, refcell_undo_redo_example,
+};
 
 fn main() {
     cell_example();
     refcell_example();
+    refcell_memo_example();
+    refcell_graph_example();
+    refcell_undo_redo_example();
 }
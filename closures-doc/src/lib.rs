@@ -3,6 +3,8 @@
 //! Internally, closures are basically structs that hold captured variables
 //! and implement one (or more) of the traits: Fn, FnMut, FnOnce.
 
+use std::time::{Duration, Instant};
+
 pub fn example_basic() {
     println!("== Example 1: Basic closure ==");
     let add_one = |x: i32| x + 1;
@@ -122,6 +124,486 @@ pub fn example_iterators() {
 }
 
 
+/// A closure stored in a struct field, borrowing from its environment.
+///
+/// `Box<dyn Fn(&str) -> bool>` alone means `+ 'static`, so a closure that
+/// borrows a local would be rejected. The explicit `+ 'a` ties the boxed
+/// closure to the lifetime of whatever it captures.
+pub struct Validator<'a> {
+    rule: Box<dyn Fn(&str) -> bool + 'a>,
+}
+
+impl<'a> Validator<'a> {
+    pub fn new<F>(rule: F) -> Self
+    where
+        F: Fn(&str) -> bool + 'a,
+    {
+        Validator { rule: Box::new(rule) }
+    }
+
+    pub fn check(&self, input: &str) -> bool {
+        (self.rule)(input)
+    }
+}
+
+pub fn example_validator_lifetimes() {
+    println!("\n== Example 8: Fn trait objects with lifetimes in struct fields ==");
+    let allow_list = ["alice", "bob"];
+
+    // The closure borrows `allow_list`, so the validator cannot outlive it.
+    let validator = Validator::new(|name| allow_list.contains(&name));
+    println!("check(\"alice\") = {}", validator.check("alice"));
+    println!("check(\"mallory\") = {}", validator.check("mallory"));
+    assert!(validator.check("alice"));
+    assert!(validator.check("bob"));
+    assert!(!validator.check("mallory"));
+
+    // Without `+ 'a` on the field this would fail to compile:
+    //   struct Validator { rule: Box<dyn Fn(&str) -> bool> } // implicitly + 'static
+    //   Validator { rule: Box::new(|n| allow_list.contains(&n)) }
+    //   error[E0597]: `allow_list` does not live long enough
+}
+
+/// Wrap a one-shot initializer: the first call runs `init`, later calls return
+/// a clone of the cached value. The returned closure owns two `Option`s as its
+/// captured state, so it is `FnMut` (it mutates them) but not `Fn`.
+pub fn lazy<T: Clone>(init: impl FnOnce() -> T) -> impl FnMut() -> T {
+    let mut init = Some(init); // FnOnce can only be called by value → take() it out
+    let mut cache: Option<T> = None;
+    move || {
+        cache
+            .get_or_insert_with(|| (init.take().expect("init already ran"))())
+            .clone()
+    }
+}
+
+pub fn example_lazy_cache() {
+    println!("\n== Example 9: Lazy-init closure cache (FnOnce inside FnMut) ==");
+    let mut runs = 0;
+    {
+        let mut expensive = lazy(|| {
+            runs += 1; // captured by &mut: counts how often init executes
+            println!("  (computing...)");
+            (1..=20u64).product::<u64>()
+        });
+        // Nothing computed yet: creating the closure is free.
+        let first = expensive();
+        let second = expensive();
+        let third = expensive();
+        println!("20! = {} (then {}, {})", first, second, third);
+        assert_eq!(first, 2_432_902_008_176_640_000);
+        assert!(first == second && second == third);
+    } // closure dropped here, releasing its &mut borrow of `runs`
+    assert_eq!(runs, 1);
+    println!("init ran {} time(s)", runs);
+}
+
+/// A closure with a tiny state machine: each call flips `next_is_a` and returns
+/// the matching value. Mutating captured state makes it `FnMut`, not `Fn`.
+pub fn toggler<T: Clone>(a: T, b: T) -> impl FnMut() -> T {
+    let mut next_is_a = true;
+    move || {
+        let out = if next_is_a { a.clone() } else { b.clone() };
+        next_is_a = !next_is_a;
+        out
+    }
+}
+
+pub fn example_toggler() {
+    println!("\n== Example 10: State-machine closure (FnMut toggler) ==");
+    let mut light = toggler("on", "off");
+    let calls = [light(), light(), light(), light()];
+    println!("light: {:?}", calls);
+    assert_eq!(calls, ["on", "off", "on", "off"]);
+
+    // Each call to `toggler` creates a new closure struct with its own state.
+    let mut t1 = toggler(1, 2);
+    let mut t2 = toggler(1, 2);
+    assert_eq!((t1(), t1(), t1()), (1, 2, 1));
+    assert_eq!(t2(), 1); // t2 unaffected by t1's calls
+    assert_eq!((t1(), t2()), (2, 2));
+
+    // A pure Fn cannot remember anything between calls: same input, same output.
+    let pure = |flag: bool| if flag { "on" } else { "off" };
+    assert_eq!((pure(true), pure(true)), ("on", "on"));
+
+    // fn call_twice<F: Fn() -> &'static str>(f: F) { f(); f(); }
+    // call_twice(toggler("on", "off")); // ❌ expected Fn, found FnMut
+}
+
+/// A suspended computation: the boxed closure runs only when [`Deferred::force`] is
+/// called. `force(self)` takes `self` by value, matching the `FnOnce` inside — the
+/// thunk can be run at most once, and the type system enforces it.
+pub struct Deferred<T> {
+    thunk: Box<dyn FnOnce() -> T>,
+}
+
+impl<T: 'static> Deferred<T> {
+    pub fn new(f: impl FnOnce() -> T + 'static) -> Self {
+        Deferred { thunk: Box::new(f) }
+    }
+
+    /// Compose `f` after the thunk without running either: the new closure
+    /// captures `self` (moved in) and forces it only when it is forced itself.
+    pub fn map<U: 'static>(self, f: impl FnOnce(T) -> U + 'static) -> Deferred<U> {
+        Deferred::new(move || f(self.force()))
+    }
+
+    pub fn force(self) -> T {
+        (self.thunk)()
+    }
+}
+
+pub fn example_deferred() {
+    println!("\n== Example 11: Deferred computation (boxed FnOnce thunk) ==");
+    use std::{cell::Cell, rc::Rc};
+
+    // Box<dyn FnOnce() -> T> is implicitly + 'static, so share the counter via Rc.
+    let runs = Rc::new(Cell::new(0));
+    let counter = Rc::clone(&runs);
+    let d = Deferred::new(move || {
+        counter.set(counter.get() + 1);
+        println!("  (thunk running)");
+        21
+    });
+    assert_eq!(runs.get(), 0); // building it ran nothing
+
+    let doubled = d.map(|x| x * 2);
+    let text = doubled.map(|x| format!("answer = {x}"));
+    assert_eq!(runs.get(), 0); // neither did mapping
+
+    let out = text.force();
+    println!("{}", out);
+    assert_eq!(out, "answer = 42");
+    assert_eq!(runs.get(), 1); // forced exactly once, through the whole chain
+
+    // A Deferred that is never forced never runs its closure.
+    let flag = Rc::new(Cell::new(false));
+    let f = Rc::clone(&flag);
+    drop(Deferred::new(move || f.set(true)));
+    assert!(!flag.get());
+
+    // force(self) consumes it:
+    //   let d = Deferred::new(|| 1);
+    //   d.force();
+    //   d.force(); // ❌ error[E0382]: use of moved value: `d`
+}
+
+/// Wrap `f` so it runs at most once per `min_interval`; calls in between are dropped.
+/// The first call always runs. The last run's `Instant` lives in the closure's state.
+pub fn throttle<F: FnMut()>(min_interval: Duration, f: F) -> impl FnMut() {
+    throttle_with_clock(min_interval, Instant::now, f)
+}
+
+// Same, with the clock injected so the demo can step time by hand.
+fn throttle_with_clock<F, C>(min_interval: Duration, mut now: C, mut f: F) -> impl FnMut()
+where
+    F: FnMut(),
+    C: FnMut() -> Instant,
+{
+    let mut last: Option<Instant> = None;
+    move || {
+        let t = now();
+        if last.is_none_or(|prev| t.duration_since(prev) >= min_interval) {
+            last = Some(t); // only successful calls restart the interval
+            f();
+        }
+    }
+}
+
+pub fn example_throttle() {
+    println!("\n== Example 12: Throttling closure (captured Instant) ==");
+    use std::cell::Cell;
+
+    // Mock clock: `elapsed` is advanced by hand, so the test is exact.
+    let start = Instant::now();
+    let elapsed = Cell::new(Duration::ZERO);
+    let mut runs = 0;
+    {
+        let mut save = throttle_with_clock(Duration::from_millis(100), || start + elapsed.get(), || runs += 1);
+        save(); // t=0: first call passes
+        for ms in [10, 50, 99] {
+            elapsed.set(Duration::from_millis(ms));
+            save(); // within 100ms of the last run: suppressed
+        }
+        elapsed.set(Duration::from_millis(100));
+        save(); // exactly one interval later: passes
+        elapsed.set(Duration::from_millis(150));
+        save(); // 50ms after that run: suppressed
+        elapsed.set(Duration::from_millis(250));
+        save(); // passes
+    }
+    println!("mock clock: 7 calls, {} ran", runs);
+    assert_eq!(runs, 3);
+
+    // Real clock: a burst is collapsed into few calls, a call after a sleep passes. How
+    // many of the burst get through depends on machine speed, so only the floor is exact
+    // (the mock clock above pins the behavior down precisely).
+    let mut hits = 0;
+    {
+        let mut ping = throttle(Duration::from_millis(20), || hits += 1);
+        for _ in 0..1_000 {
+            ping();
+        }
+        std::thread::sleep(Duration::from_millis(25));
+        ping();
+    }
+    println!("real clock: burst of 1000 + 1 after sleep → {} ran", hits);
+    assert!(hits >= 2); // the first call and the one after the sleep
+}
+
+/// An ordered list of transforms, each a different closure type behind the same
+/// `Box<dyn Fn(T) -> T>`. `run` threads the value through the stages front to back.
+pub struct Pipeline<T> {
+    stages: Vec<Box<dyn Fn(T) -> T>>,
+}
+
+impl<T> Pipeline<T> {
+    pub fn new() -> Self {
+        Pipeline { stages: Vec::new() }
+    }
+
+    /// Appends a stage; returns `&mut Self` so calls can be chained.
+    pub fn add_stage(&mut self, f: impl Fn(T) -> T + 'static) -> &mut Self {
+        self.stages.push(Box::new(f));
+        self
+    }
+
+    pub fn run(&self, input: T) -> T {
+        self.stages.iter().fold(input, |acc, stage| stage(acc))
+    }
+}
+
+impl<T> Default for Pipeline<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn example_pipeline() {
+    println!("\n== Example 13: Pipeline of boxed transforms ==");
+    // Three different closure types in one Vec: a plain closure, one capturing a
+    // value by move, and a fn item.
+    let offset = 10;
+    fn halve(x: i64) -> i64 { x / 2 }
+    let mut p = Pipeline::new();
+    p.add_stage(|x: i64| x * 3).add_stage(move |x| x + offset).add_stage(halve);
+
+    println!("pipeline(4) = {}", p.run(4)); // (4 * 3 + 10) / 2
+    assert_eq!(p.run(4), 11);
+    assert_eq!(p.run(0), 5);
+    assert_eq!(p.run(-2), 2); // `Fn` stages: run as often as you like
+
+    // Order matters: the same stages reversed give a different result.
+    let mut rev = Pipeline::new();
+    rev.add_stage(halve).add_stage(move |x| x + offset).add_stage(|x: i64| x * 3);
+    assert_eq!(rev.run(4), 36); // (4 / 2 + 10) * 3
+
+    // No stages: the input comes back unchanged.
+    let empty: Pipeline<String> = Pipeline::default();
+    assert_eq!(empty.run("as is".to_string()), "as is");
+}
+
+pub fn example_closure_capture_pitfall() {
+    println!("\n== Example 14: Closure stored in the Rc it captures (cycle) vs Weak ==");
+    use std::{cell::{Cell, RefCell}, rc::{Rc, Weak}};
+
+    // A widget that owns its own click handler. The handler wants to touch the widget.
+    struct Button {
+        clicks: Cell<u32>,
+        on_click: RefCell<Option<Box<dyn Fn()>>>,
+    }
+    impl Button {
+        fn new() -> Rc<Button> {
+            Rc::new(Button { clicks: Cell::new(0), on_click: RefCell::new(None) })
+        }
+        fn click(&self) {
+            if let Some(handler) = self.on_click.borrow().as_ref() {
+                handler();
+            }
+        }
+    }
+
+    // Bug: `move` captures a strong Rc, and the closure is stored *inside* that Rc's value:
+    // Button → on_click → closure → Rc<Button>. Dropping the last outside handle leaves 1.
+    let button = Button::new();
+    let me = Rc::clone(&button);
+    *button.on_click.borrow_mut() = Some(Box::new(move || me.clicks.set(me.clicks.get() + 1)));
+    button.click();
+    let probe = Rc::downgrade(&button);
+    assert_eq!(Rc::strong_count(&button), 2); // ours + the closure's
+    drop(button);
+    println!("buggy: after drop, strong = {} (leaked)", probe.strong_count());
+    assert_eq!(probe.strong_count(), 1); // the closure keeps the Button alive, forever
+    assert!(probe.upgrade().is_some());
+    // Clean up for the demo: reach in and drop the handler, which breaks the cycle.
+    probe.upgrade().unwrap().on_click.borrow_mut().take();
+    assert_eq!(probe.strong_count(), 0);
+
+    // Fix: capture a Weak and upgrade on each call. The closure no longer owns the Button.
+    let button = Button::new();
+    let me: Weak<Button> = Rc::downgrade(&button);
+    *button.on_click.borrow_mut() = Some(Box::new(move || {
+        if let Some(b) = me.upgrade() {
+            b.clicks.set(b.clicks.get() + 1);
+        } // else: the Button is gone; nothing to do
+    }));
+    button.click();
+    button.click();
+    assert_eq!(button.clicks.get(), 2); // still works while the Button lives
+    let probe = Rc::downgrade(&button);
+    assert_eq!(Rc::strong_count(&button), 1); // only ours
+    drop(button);
+    println!("fixed: after drop, strong = {}", probe.strong_count());
+    assert_eq!(probe.strong_count(), 0); // freed, handler and all
+    assert!(probe.upgrade().is_none());
+}
+
+/// Streaming median: the returned closure takes one value per call and returns the
+/// median of everything seen so far, in O(log n) per value. Its captured state is two
+/// heaps that split the values in half: `low` (a max-heap of the smaller half) and
+/// `high` (a min-heap, via `Reverse`, of the larger half), with `low` allowed one extra.
+/// The median is then `low`'s top, or the mean of both tops for an even count.
+/// `f64` isn't `Ord`, so values are wrapped in `TotalF64`, ordered by `f64::total_cmp`.
+pub fn running_median() -> impl FnMut(f64) -> f64 {
+    use std::{cmp::Reverse, collections::BinaryHeap};
+    let mut low: BinaryHeap<TotalF64> = BinaryHeap::new();
+    let mut high: BinaryHeap<Reverse<TotalF64>> = BinaryHeap::new();
+    move |x| {
+        // Route through `low` so its max lands in `high`, then rebalance the sizes.
+        low.push(TotalF64(x));
+        high.push(Reverse(low.pop().unwrap()));
+        if high.len() > low.len() {
+            low.push(high.pop().unwrap().0);
+        }
+        let lo = low.peek().unwrap().0;
+        match high.peek() {
+            Some(Reverse(hi)) if low.len() == high.len() => (lo + hi.0) / 2.0,
+            _ => lo,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct TotalF64(f64);
+
+impl Ord for TotalF64 {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl PartialOrd for TotalF64 {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for TotalF64 {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for TotalF64 {}
+
+pub fn example_running_median() {
+    println!("\n== Example 15: Streaming median (FnMut with two BinaryHeaps as state) ==");
+    fn brute_median(prefix: &[f64]) -> f64 {
+        let mut v = prefix.to_vec();
+        v.sort_by(f64::total_cmp);
+        let mid = v.len() / 2;
+        if v.len() % 2 == 1 { v[mid] } else { (v[mid - 1] + v[mid]) / 2.0 }
+    }
+
+    let values = [5.0, 15.0, 1.0, 3.0, 8.0, 7.0, 9.0, 10.0, 20.0, 2.0, -4.0, 3.0, 3.0];
+    let mut median = running_median();
+    let medians: Vec<f64> = values.iter().map(|&x| median(x)).collect();
+    println!("values  = {:?}\nmedians = {:?}", values, medians);
+    assert_eq!(medians[..4], [5.0, 10.0, 5.0, 4.0]);
+    for i in 0..values.len() {
+        assert_eq!(medians[i], brute_median(&values[..=i]), "after {} values", i + 1);
+    }
+
+    // Longer, pseudo-random stream with duplicates and negatives.
+    let mut seed = 0x2545_F491_u32;
+    let stream: Vec<f64> = (0..500)
+        .map(|_| {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            f64::from(seed % 200) - 100.0
+        })
+        .collect();
+    let mut median = running_median();
+    for (i, &x) in stream.iter().enumerate() {
+        assert_eq!(median(x), brute_median(&stream[..=i]));
+    }
+
+    // Each call to running_median() starts from empty state.
+    let mut fresh = running_median();
+    assert_eq!(fresh(42.0), 42.0);
+}
+
+/// Calls `f` on every item as a `&str` borrowed from the slice. The bound reads "for every
+/// lifetime `'a`, `F: Fn(&'a str) -> usize`": `f` must accept a borrow of *any* lifetime,
+/// including the short, unnameable one created for each `&items[i]` inside the loop. A
+/// plain lifetime parameter on `apply_to_all` couldn't express that — the caller would
+/// pick one `'a`, and a borrow of a local inside this function can't outlive it.
+/// (`F: Fn(&str) -> usize` is the same bound; elision writes the `for<'a>` for you.)
+pub fn apply_to_all<F>(items: &[String], f: F) -> Vec<usize>
+where
+    F: for<'a> Fn(&'a str) -> usize,
+{
+    items.iter().map(|s| f(s.as_str())).collect()
+}
+
+/// Where elision can't help: the output borrows from the input, so the HRTB has to tie them
+/// together explicitly. Each call gets its own `'a`, and the result lives as long as *that*
+/// argument.
+fn map_borrowed<'s, F>(items: &'s [String], f: F) -> Vec<&'s str>
+where
+    F: for<'a> Fn(&'a str) -> &'a str,
+{
+    items.iter().map(|s| f(s)).collect()
+}
+
+pub fn example_hrtb() {
+    println!("\n== Example 16: Higher-ranked trait bounds (for<'a> Fn(&'a str)) ==");
+    let items: Vec<String> = ["alpha", "be", "", "gamma ray"].map(String::from).into();
+    let lens = apply_to_all(&items, |s| s.len());
+    println!("lengths = {:?}", lens);
+    assert_eq!(lens, [5, 2, 0, 9]);
+    assert_eq!(apply_to_all(&items, |s| s.split_whitespace().count()), [1, 1, 0, 2]);
+
+    // A closure may borrow from the very slice it's applied to: its captures only need to
+    // outlive the call, while the `&'a str` argument is still universally quantified.
+    let longest: &str = items.iter().map(String::as_str).max_by_key(|s| s.len()).unwrap();
+    let shorter_by = apply_to_all(&items, |s| longest.len() - s.len());
+    assert_eq!(shorter_by, [4, 7, 9, 0]);
+
+    // Function items are higher-ranked too, and `str::len` fits the bound directly.
+    assert_eq!(apply_to_all(&items, str::len), lens);
+
+    // Returning a borrow of the argument: the closure's return type has to be pinned to
+    // `&'a str` by the bound — the results borrow `items`, not the closure.
+    let firsts = map_borrowed(&items, |s| s.split(' ').next().unwrap_or(s));
+    assert_eq!(firsts, ["alpha", "be", "", "gamma"]);
+    let trimmed = map_borrowed(&items, |s| s.get(..2).unwrap_or(s));
+    assert_eq!(trimmed, ["al", "be", "", "ga"]);
+
+    // Pitfall (doesn't compile, so shown as a comment): storing the closure in a `let` first
+    // can make inference pick a single lifetime instead of a higher-ranked signature:
+    //     let first = |s: &str| -> &str { &s[..1] };   // error: lifetime may not live long enough
+    // Passing the closure straight into a function with the `for<'a>` bound (as above)
+    // avoids it, because the bound tells inference which signature to give the closure.
+    fn identity_hr<F: for<'a> Fn(&'a str) -> &'a str>(f: F) -> F {
+        f
+    }
+    let first = identity_hr(|s| s.get(..1).unwrap_or(s));
+    assert_eq!(map_borrowed(&items, first), ["a", "b", "", "g"]);
+}
+
 /*
 Docs-style notes:
 
@@ -147,6 +629,24 @@ Closures vs functions:
 - Closures: may capture environment, so type is unique and inferred.
 - Both can be used where Fn traits are expected.
 
+Storing closures in structs:
+- Generic field `F: Fn(..)` -> zero-cost, but the struct type depends on F.
+- `Box<dyn Fn(..)>` -> one struct type for any closure, defaults to `+ 'static`.
+- `Box<dyn Fn(..) + 'a>` -> allows closures that borrow locals (see Validator).
+- `Vec<Box<dyn Fn(T) -> T>>` -> a list of *different* closures run in order (see Pipeline).
+- A closure stored inside an `Rc`'s value must not `move`-capture that same `Rc`: the
+  cycle never frees. Capture `Rc::downgrade(&rc)` and `upgrade()` inside the closure.
+
+Closures with their own state:
+- Captured `move` variables act like private struct fields that persist between calls.
+- Mutating them makes the closure FnMut (e.g. `lazy` caching an Option<T>).
+- Calling a captured FnOnce from an FnMut needs `Option::take()` to move it out once.
+- Each closure value has its own copy of that state (two togglers never interfere).
+- Captured state can be time, too: `throttle` keeps the last run's `Instant` and
+  drops calls that come too soon.
+- A boxed `FnOnce` is a lazy value: `Deferred::force(self)` runs it once; `map` wraps
+  it in another closure without running anything.
+
 Performance:
 - Zero-cost abstraction: closure structs are monomorphized like generics.
 - No runtime overhead compared to writing the struct manually.
@@ -6,6 +6,15 @@ use closures_doc::{
     example_fn_traits,
     example_returning_closure,
     example_iterators,
+    example_validator_lifetimes,
+    example_lazy_cache,
+    example_toggler,
+    example_deferred,
+    example_throttle,
+    example_pipeline,
+    example_closure_capture_pitfall,
+    example_running_median,
+    example_hrtb,
 };
 
 fn main() {
@@ -16,4 +25,13 @@ fn main() {
     example_fn_traits();
     example_returning_closure();
     example_iterators();
+    example_validator_lifetimes();
+    example_lazy_cache();
+    example_toggler();
+    example_deferred();
+    example_throttle();
+    example_pipeline();
+    example_closure_capture_pitfall();
+    example_running_median();
+    example_hrtb();
 }
@@ -6,7 +6,7 @@
 //! - Prefer the `entry` API for “insert-or-update” without double lookups.
 //! - For lookups with borrowed forms (e.g., `String` key, `&str` lookup) use `get::<Q>` patterns.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::hash::Hash;
 
 pub fn ex_basics() {
@@ -205,6 +205,491 @@ pub fn ex_common_patterns() {
 }
 
 
+/// Insertion-ordered map ("IndexMap-lite"): entries live in a `Vec<(K, V)>` in
+/// insertion order, and a `HashMap<K, usize>` maps each key to its slot, so
+/// lookups stay O(1) while iteration order is deterministic.
+pub struct OrderedMap<K: Eq + Hash + Clone, V> {
+    index: HashMap<K, usize>,
+    entries: Vec<(K, V)>,
+}
+
+impl<K: Eq + Hash + Clone, V> OrderedMap<K, V> {
+    pub fn new() -> Self {
+        OrderedMap { index: HashMap::new(), entries: Vec::new() }
+    }
+
+    /// Overwriting an existing key keeps its original position.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.index.get(&key) {
+            Some(&i) => Some(std::mem::replace(&mut self.entries[i].1, value)),
+            None => {
+                self.index.insert(key.clone(), self.entries.len());
+                self.entries.push((key, value));
+                None
+            }
+        }
+    }
+
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: std::borrow::Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.index.get(key).map(|&i| &self.entries[i].1)
+    }
+
+    /// O(1) removal via `swap_remove`: the *last* entry moves into the freed
+    /// slot (so its position changes); every other entry keeps its order.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: std::borrow::Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let i = self.index.remove(key)?;
+        let (_, value) = self.entries.swap_remove(i);
+        if let Some((moved_key, _)) = self.entries.get(i) {
+            self.index.insert(moved_key.clone(), i); // fix up the moved entry's slot
+        }
+        Some(value)
+    }
+
+    pub fn len(&self) -> usize { self.entries.len() }
+
+    pub fn is_empty(&self) -> bool { self.entries.is_empty() }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.entries.iter().map(|(k, v)| (k, v))
+    }
+}
+
+impl<K: Eq + Hash + Clone, V> Default for OrderedMap<K, V> {
+    fn default() -> Self { Self::new() }
+}
+
+pub fn ex_ordered_map() {
+    println!("\n== OrderedMap (insertion order + O(1) lookup) ==");
+    let mut m: OrderedMap<String, i32> = OrderedMap::new();
+    for (k, v) in [("zeta", 1), ("alpha", 2), ("mid", 3), ("beta", 4)] {
+        m.insert(k.to_string(), v);
+    }
+    let keys = |m: &OrderedMap<String, i32>| m.iter().map(|(k, _)| k.clone()).collect::<Vec<_>>();
+    println!("iter order = {:?}", m.iter().collect::<Vec<_>>());
+    assert_eq!(keys(&m), ["zeta", "alpha", "mid", "beta"]); // insertion order, every run
+
+    // Overwrite keeps the original slot.
+    assert_eq!(m.insert("alpha".to_string(), 20), Some(2));
+    assert_eq!(keys(&m), ["zeta", "alpha", "mid", "beta"]);
+    assert_eq!(m.get("alpha"), Some(&20)); // &str lookup on String keys
+
+    // swap_remove: last entry ("beta") fills the hole left by "zeta".
+    assert_eq!(m.remove("zeta"), Some(1));
+    println!("after remove(zeta) = {:?}", m.iter().collect::<Vec<_>>());
+    assert_eq!(keys(&m), ["beta", "alpha", "mid"]);
+    assert_eq!(m.get("beta"), Some(&4)); // index was fixed up
+    assert_eq!(m.get("mid"), Some(&3));
+
+    // Removing the last entry moves nothing.
+    assert_eq!(m.remove("mid"), Some(3));
+    assert_eq!(keys(&m), ["beta", "alpha"]);
+    assert_eq!(m.remove("missing"), None);
+    assert_eq!(m.len(), 2);
+}
+
+/// String-keyed map that ignores case: keys are folded with `to_lowercase` on both
+/// insert and lookup, and each entry also remembers the key exactly as first inserted,
+/// so `iter` shows `"Content-Type"`, not `"content-type"`.
+#[derive(Debug)]
+pub struct CaseInsensitiveMap<V> {
+    entries: HashMap<String, (String, V)>, // folded key -> (original key, value)
+}
+
+impl<V> CaseInsensitiveMap<V> {
+    pub fn new() -> Self {
+        CaseInsensitiveMap { entries: HashMap::new() }
+    }
+
+    /// Overwriting under a different casing replaces the value but keeps the first casing.
+    pub fn insert(&mut self, key: &str, value: V) -> Option<V> {
+        use std::collections::hash_map::Entry;
+        match self.entries.entry(key.to_lowercase()) {
+            Entry::Occupied(mut e) => Some(std::mem::replace(&mut e.get_mut().1, value)),
+            Entry::Vacant(e) => {
+                e.insert((key.to_string(), value));
+                None
+            }
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.entries.get(&key.to_lowercase()).map(|(_, v)| v)
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<V> {
+        self.entries.remove(&key.to_lowercase()).map(|(_, v)| v)
+    }
+
+    pub fn len(&self) -> usize { self.entries.len() }
+
+    pub fn is_empty(&self) -> bool { self.entries.is_empty() }
+
+    /// Original-casing keys, in (unstable) hash order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &V)> {
+        self.entries.values().map(|(k, v)| (k.as_str(), v))
+    }
+}
+
+impl<V> Default for CaseInsensitiveMap<V> {
+    fn default() -> Self { Self::new() }
+}
+
+pub fn ex_case_insensitive_map() {
+    println!("\n== CaseInsensitiveMap (folded lookup, original casing kept) ==");
+    let mut headers = CaseInsensitiveMap::new();
+    assert_eq!(headers.insert("Content-Type", "text/html"), None);
+    assert_eq!(headers.insert("X-Request-Id", "42"), None);
+
+    // Any casing of the query finds the entry.
+    for q in ["Content-Type", "content-type", "CONTENT-TYPE", "cOnTeNt-TyPe"] {
+        assert_eq!(headers.get(q), Some(&"text/html"), "{q}");
+    }
+    assert_eq!(headers.get("content_type"), None); // folding is about case only
+
+    // Same key, different casing: overwrites, first casing wins.
+    assert_eq!(headers.insert("CONTENT-TYPE", "application/json"), Some("text/html"));
+    assert_eq!(headers.len(), 2);
+    let mut shown: Vec<_> = headers.iter().collect();
+    shown.sort();
+    println!("iter = {shown:?}");
+    assert_eq!(shown, [("Content-Type", &"application/json"), ("X-Request-Id", &"42")]);
+
+    // `to_lowercase` is Unicode-aware, but it is not full case folding (ß vs SS).
+    headers.insert("Été", "summer");
+    assert_eq!(headers.get("ÉTÉ"), Some(&"summer"));
+    headers.insert("Straße", "street");
+    assert_eq!(headers.get("STRASSE"), None);
+
+    assert_eq!(headers.remove("x-request-id"), Some("42"));
+    assert_eq!(headers.remove("X-Request-Id"), None);
+    assert_eq!(headers.len(), 3);
+}
+
+// Deterministic views of a HashMap: copy the entries out and sort them, so printing,
+// snapshots and diffs don't depend on the hasher's random seed.
+fn sorted_entries<K: Ord + Clone, V: Clone>(m: &HashMap<K, V>) -> Vec<(K, V)> {
+    let mut entries: Vec<(K, V)> = m.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+    entries.sort_by(|a, b| a.0.cmp(&b.0)); // keys are unique → total order
+    entries
+}
+
+// Ties on value fall back to the key, otherwise equal values would still come out
+// in hash order.
+fn sorted_by_value<K: Ord + Clone, V: Ord + Clone>(m: &HashMap<K, V>) -> Vec<(K, V)> {
+    let mut entries = sorted_entries(m);
+    entries.sort_by(|a, b| a.1.cmp(&b.1)); // stable sort keeps key order among ties
+    entries
+}
+
+pub fn ex_deterministic_iteration() {
+    println!("\n== Deterministic iteration (sorted snapshots) ==");
+    let scores = HashMap::from([("carol", 7), ("alice", 9), ("bob", 7), ("dave", 1)]);
+    let before = scores.clone();
+
+    let by_key = sorted_entries(&scores);
+    let by_value = sorted_by_value(&scores);
+    println!("raw iteration   = {:?}", scores); // order varies between runs
+    println!("sorted by key   = {:?}", by_key);
+    println!("sorted by value = {:?}", by_value);
+    assert_eq!(by_key, [("alice", 9), ("bob", 7), ("carol", 7), ("dave", 1)]);
+    assert_eq!(by_value, [("dave", 1), ("bob", 7), ("carol", 7), ("alice", 9)]);
+
+    // Same content built in a different order (and with a fresh random hasher)
+    // gives identical sorted output.
+    let mut rebuilt = HashMap::new();
+    for (k, v) in [("dave", 1), ("bob", 7), ("alice", 9), ("carol", 7)] {
+        rebuilt.insert(k, v);
+    }
+    assert_eq!(sorted_entries(&rebuilt), by_key);
+    assert_eq!(sorted_by_value(&rebuilt), by_value);
+
+    assert_eq!(scores, before); // helpers only read the map
+}
+
+// Histogram via the entry API: bucket `i` covers `[i * width, (i + 1) * width)`, so a
+// value on a boundary lands in the upper bucket and negatives use `floor`, not truncation
+// (`-0.2 / 0.5` → bucket -1, where `as i64` alone would give 0). Counting goes into a
+// BTreeMap so buckets come out sorted. NaN/±inf have no bucket and are skipped.
+fn histogram(samples: &[f64], bucket_width: f64) -> BTreeMap<i64, usize> {
+    assert!(bucket_width > 0.0, "bucket_width must be positive");
+    let mut counts = BTreeMap::new();
+    for &x in samples.iter().filter(|x| x.is_finite()) {
+        let bucket = (x / bucket_width).floor() as i64;
+        *counts.entry(bucket).or_insert(0) += 1;
+    }
+    counts
+}
+
+pub fn ex_histogram() {
+    println!("\n== Histogram (entry API + BTreeMap buckets) ==");
+    let samples = [0.1, 0.25, 0.5, 0.75, 1.0, 2.4, -0.2, -0.5, -1.0, f64::NAN];
+    let h = histogram(&samples, 0.5);
+    for (bucket, n) in &h {
+        let lo = *bucket as f64 * 0.5;
+        println!("[{lo:>5.1}, {:>4.1}) {}", lo + 0.5, "#".repeat(*n));
+    }
+    // Boundaries go up: 0.5 → 1, 1.0 → 2, -0.5 → -1, -1.0 → -2; -0.2 floors to -1.
+    let expected = BTreeMap::from([(-2, 1), (-1, 2), (0, 2), (1, 2), (2, 1), (4, 1)]);
+    assert_eq!(h, expected);
+    assert_eq!(h.values().sum::<usize>(), samples.len() - 1); // NaN skipped
+    assert_eq!(h.keys().copied().collect::<Vec<_>>(), [-2, -1, 0, 1, 2, 4]); // sorted, gaps omitted
+
+    assert_eq!(histogram(&[], 1.0), BTreeMap::new());
+    assert_eq!(histogram(&[-3.0, -2.9, 3.0], 1.0), BTreeMap::from([(-3, 2), (3, 1)]));
+}
+
+// Top-k most frequent items: count with the entry API (remembering where each item was
+// first seen), then keep the k best in a size-k min-heap, so the cost is O(n + d log k)
+// for d distinct items instead of sorting all d. Ties on count go to the item that
+// appeared first in `items` — deterministic even though HashMap order is not, and it
+// needs no `Ord` on T.
+fn top_k<T: Eq + Hash + Clone>(items: &[T], k: usize) -> Vec<(T, usize)> {
+    use std::cmp::Reverse;
+    use std::collections::BinaryHeap;
+    let mut counts: HashMap<&T, (usize, usize)> = HashMap::new(); // item -> (count, first index)
+    for (i, item) in items.iter().enumerate() {
+        counts.entry(item).or_insert((0, i)).0 += 1;
+    }
+    // Rank = (count, Reverse(first)): bigger is better. `Reverse` around it makes the
+    // heap's top the *worst* of the kept k, which is the one to evict. Sized from the data,
+    // not from `k`: a caller's "give me everything" (`usize::MAX`) must not allocate that.
+    let mut heap = BinaryHeap::with_capacity(k.min(counts.len()).saturating_add(1));
+    for &(count, first) in counts.values() {
+        heap.push(Reverse((count, Reverse(first))));
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+    // `into_sorted_vec` is ascending in `Reverse(rank)`, i.e. best rank first.
+    heap.into_sorted_vec().into_iter().map(|Reverse((count, Reverse(first)))| (items[first].clone(), count)).collect()
+}
+
+pub fn ex_top_k() {
+    println!("\n== Top-k by frequency (entry API + BinaryHeap) ==");
+    let words: Vec<&str> = "the cat and the dog and the bird saw a cat".split(' ').collect();
+    let top = top_k(&words, 3);
+    println!("top 3 = {:?}", top);
+    assert_eq!(top, [("the", 3), ("cat", 2), ("and", 2)]); // cat before and: seen first
+
+    // Ties all the way down are broken by first appearance.
+    assert_eq!(top_k(&words, 6)[3..], [("dog", 1), ("bird", 1), ("saw", 1)]);
+
+    // k larger than the number of distinct items: everything, still ranked.
+    let all = top_k(&[3, 1, 3, 2, 3, 1], 10);
+    assert_eq!(all, [(3, 3), (1, 2), (2, 1)]);
+    assert_eq!(all.iter().map(|(_, n)| n).sum::<usize>(), 6);
+    // Huge k doesn't allocate (or overflow) based on k.
+    assert_eq!(top_k(&["x", "y", "x"], 1 << 40), [("x", 2), ("y", 1)]);
+    assert_eq!(top_k(&["x", "y", "x"], usize::MAX).len(), 2);
+
+    // k == 0 and empty input.
+    assert!(top_k(&words, 0).is_empty());
+    assert!(top_k::<u8>(&[], 3).is_empty());
+
+    // Same answer as a full sort of the counts (reference implementation).
+    let data: Vec<u32> = (0..2_000u32).map(|i| (i * i + 7 * i) % 37).collect();
+    let mut full: Vec<(u32, usize, usize)> = vec![]; // (item, count, first)
+    for (i, &x) in data.iter().enumerate() {
+        match full.iter_mut().find(|e| e.0 == x) {
+            Some(e) => e.1 += 1,
+            None => full.push((x, 1, i)),
+        }
+    }
+    full.sort_by(|a, b| b.1.cmp(&a.1).then(a.2.cmp(&b.2)));
+    let expected: Vec<(u32, usize)> = full.iter().take(5).map(|&(x, n, _)| (x, n)).collect();
+    assert_eq!(top_k(&data, 5), expected);
+}
+
+// Deep merge of a two-level map, e.g. region -> product -> units sold.
+// Outer: `entry(k).or_default()` creates the inner map only if missing, so an
+// existing inner map is extended in place instead of replaced. Inner: the same
+// entry trick sums colliding leaves. `other` is consumed, so keys move, not clone.
+fn merge_deep(base: &mut HashMap<String, HashMap<String, i32>>, other: HashMap<String, HashMap<String, i32>>) {
+    for (outer, inner) in other {
+        let slot = base.entry(outer).or_default();
+        for (k, v) in inner {
+            *slot.entry(k).or_insert(0) += v;
+        }
+    }
+}
+
+pub fn ex_merge_deep() {
+    println!("\n== Deep merge of nested maps (entry API at both levels) ==");
+    fn nested(pairs: &[(&str, &[(&str, i32)])]) -> HashMap<String, HashMap<String, i32>> {
+        pairs
+            .iter()
+            .map(|(o, inner)| (o.to_string(), inner.iter().map(|(k, v)| (k.to_string(), *v)).collect()))
+            .collect()
+    }
+
+    // Disjoint outer keys: `other`'s groups are simply added.
+    let mut base = nested(&[("eu", &[("apples", 3)])]);
+    merge_deep(&mut base, nested(&[("us", &[("pears", 2)])]));
+    assert_eq!(base, nested(&[("eu", &[("apples", 3)]), ("us", &[("pears", 2)])]));
+
+    // Same outer key, disjoint inner keys: the inner map is extended, not replaced.
+    merge_deep(&mut base, nested(&[("eu", &[("plums", 5)])]));
+    assert_eq!(base["eu"], nested(&[("eu", &[("apples", 3), ("plums", 5)])])["eu"]);
+
+    // Fully overlapping keys: leaves are summed.
+    merge_deep(&mut base, nested(&[("eu", &[("apples", 10), ("plums", -1)]), ("us", &[("pears", 1)])]));
+    println!("merged = {:?}", sorted_entries(&base.iter().map(|(k, v)| (k.clone(), sorted_entries(v))).collect()));
+    assert_eq!(base, nested(&[("eu", &[("apples", 13), ("plums", 4)]), ("us", &[("pears", 3)])]));
+
+    // Merging an empty map is a no-op; merging into an empty map copies.
+    let snapshot = base.clone();
+    merge_deep(&mut base, HashMap::new());
+    assert_eq!(base, snapshot);
+    let mut empty = HashMap::new();
+    merge_deep(&mut empty, snapshot.clone());
+    assert_eq!(empty, snapshot);
+}
+
+/// Prefix tree over `char`s: every node is itself a `Trie`, with its children in a
+/// `HashMap<char, Trie>` and a flag marking "a word ends here". Insert/lookup cost
+/// O(word length), independent of how many words are stored.
+#[derive(Debug, Default)]
+pub struct Trie {
+    children: HashMap<char, Trie>,
+    is_word: bool,
+}
+
+impl Trie {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `false` if the word was already present.
+    pub fn insert(&mut self, word: &str) -> bool {
+        // `entry().or_default()` creates the missing nodes along the path.
+        let node = word.chars().fold(self, |node, c| node.children.entry(c).or_default());
+        !std::mem::replace(&mut node.is_word, true)
+    }
+
+    /// Exact match only: a stored prefix of a word doesn't count.
+    pub fn contains(&self, word: &str) -> bool {
+        self.node(word).is_some_and(|n| n.is_word)
+    }
+
+    /// Every stored word starting with `prefix` (including `prefix` itself if stored),
+    /// sorted — child iteration is in hash order, so the result is sorted at the end.
+    pub fn with_prefix(&self, prefix: &str) -> Vec<String> {
+        let mut out = vec![];
+        if let Some(node) = self.node(prefix) {
+            node.collect(&mut prefix.to_string(), &mut out);
+        }
+        out.sort();
+        out
+    }
+
+    fn node(&self, path: &str) -> Option<&Trie> {
+        path.chars().try_fold(self, |node, c| node.children.get(&c))
+    }
+
+    // Depth-first walk; `buf` holds the path from the root and is restored on the way back.
+    fn collect(&self, buf: &mut String, out: &mut Vec<String>) {
+        if self.is_word {
+            out.push(buf.clone());
+        }
+        for (&c, child) in &self.children {
+            buf.push(c);
+            child.collect(buf, out);
+            buf.pop();
+        }
+    }
+}
+
+pub fn ex_trie() {
+    println!("\n== Trie (nested HashMap<char, Trie>) ==");
+    let mut t = Trie::new();
+    for w in ["car", "cart", "care", "cat", "dog", "do"] {
+        assert!(t.insert(w));
+    }
+    assert!(!t.insert("cat")); // duplicate
+
+    // Exact matches: a path that exists but isn't marked as a word is not contained.
+    assert!(t.contains("car") && t.contains("do") && t.contains("dog"));
+    assert!(!t.contains("ca") && !t.contains("d") && !t.contains("cars") && !t.contains(""));
+
+    println!("with_prefix(\"ca\") = {:?}", t.with_prefix("ca"));
+    assert_eq!(t.with_prefix("ca"), ["car", "care", "cart", "cat"]);
+    // A prefix that is itself a word is part of its own completions.
+    assert_eq!(t.with_prefix("car"), ["car", "care", "cart"]);
+    assert_eq!(t.with_prefix("do"), ["do", "dog"]);
+    assert_eq!(t.with_prefix("cart"), ["cart"]);
+    // No completions: the path runs out, or runs past every word.
+    assert!(t.with_prefix("x").is_empty());
+    assert!(t.with_prefix("carts").is_empty());
+    // Empty prefix = every word.
+    assert_eq!(t.with_prefix("").len(), 6);
+
+    // Non-ASCII works too: nodes are keyed by `char`, not byte.
+    t.insert("café");
+    assert_eq!(t.with_prefix("caf"), ["café"]);
+}
+
+// HashSet<T> is a HashMap<T, ()>: same hashing, same `Eq + Hash` bound, same random order.
+// The set operations return *lazy iterators* of references borrowing both sets; collect
+// them (here into a sorted Vec, since iteration order isn't stable) or use the
+// `&a | &b`, `&a & &b`, `&a - &b`, `&a ^ &b` operators, which build a new owned set.
+fn sorted<T: Ord + Clone>(items: impl IntoIterator<Item = T>) -> Vec<T> {
+    let mut v: Vec<T> = items.into_iter().collect();
+    v.sort();
+    v
+}
+
+pub fn ex_hashset_ops() {
+    println!("\n== HashSet set operations ==");
+    use std::collections::HashSet;
+    let rust: HashSet<&str> = HashSet::from(["alice", "bob", "carol", "dave"]);
+    let go: HashSet<&str> = HashSet::from(["carol", "dave", "erin"]);
+
+    let union = sorted(rust.union(&go).copied());
+    let both = sorted(rust.intersection(&go).copied());
+    let only_rust = sorted(rust.difference(&go).copied());
+    let only_go = sorted(go.difference(&rust).copied()); // difference is not symmetric
+    let exactly_one = sorted(rust.symmetric_difference(&go).copied());
+    println!("union = {union:?}\nintersection = {both:?}\nrust - go = {only_rust:?}\nsymmetric = {exactly_one:?}");
+    assert_eq!(union, ["alice", "bob", "carol", "dave", "erin"]);
+    assert_eq!(both, ["carol", "dave"]);
+    assert_eq!(only_rust, ["alice", "bob"]);
+    assert_eq!(only_go, ["erin"]);
+    assert_eq!(exactly_one, ["alice", "bob", "erin"]);
+
+    // Operator forms produce owned HashSets (elements cloned) with the same contents.
+    assert_eq!(sorted(&rust | &go), union);
+    assert_eq!(sorted(&rust & &go), both);
+    assert_eq!(sorted(&rust - &go), only_rust);
+    assert_eq!(sorted(&rust ^ &go), exactly_one);
+    // Identities: |A ∪ B| = |A| + |B| - |A ∩ B|; symmetric = union minus intersection.
+    assert_eq!(union.len(), rust.len() + go.len() - both.len());
+    assert_eq!(sorted(&(&rust | &go) - &(&rust & &go)), exactly_one);
+
+    // Predicates.
+    let seniors: HashSet<&str> = HashSet::from(["alice", "carol"]);
+    let interns: HashSet<&str> = HashSet::from(["zoe", "yann"]);
+    assert!(seniors.is_subset(&rust) && rust.is_superset(&seniors));
+    assert!(!rust.is_subset(&go));
+    assert!(interns.is_disjoint(&rust) && interns.is_disjoint(&go));
+    assert!(!rust.is_disjoint(&go)); // they share carol and dave
+    let empty: HashSet<&str> = HashSet::new();
+    assert!(empty.is_subset(&go) && empty.is_disjoint(&go)); // vacuously true
+    assert!(rust.is_subset(&rust)); // every set is a subset of itself
+
+    // `insert` reports whether the value was new — handy for "seen before?" checks.
+    let mut seen = HashSet::new();
+    let firsts: Vec<_> = [3, 1, 3, 2, 1].into_iter().filter(|x| seen.insert(*x)).collect();
+    assert_eq!(firsts, [3, 1, 2]);
+}
+
 /*
 Docs-style notes:
 
@@ -262,12 +747,23 @@ FUNCTION SIGNATURES (when designing APIs)
 - Take ownership: `fn f<K: Eq + Hash, V>(m: HashMap<K, V>) -> ...`
 - Accept “map-like” iterables: `fn f<I, K, V>(it: I) where I: IntoIterator<Item=(K,V)>`
 
+HASHSET
+- `HashSet<T>` ≈ `HashMap<T, ()>`: same bounds, same arbitrary order. `insert` returns
+  `false` if the value was already present.
+- `union` / `intersection` / `difference` / `symmetric_difference` are lazy iterators of `&T`;
+  `&a | &b`, `&a & &b`, `&a - &b`, `&a ^ &b` build new owned sets (needs `T: Clone`).
+- Predicates: `is_subset`, `is_superset`, `is_disjoint`.
+
 WHEN NOT TO USE HASHMAP
 - Need ordered iteration / range queries → use `BTreeMap`.
-- Need stable insertion order → consider `indexmap::IndexMap` (external crate).
+- Need stable insertion order → consider `indexmap::IndexMap` (external crate),
+  or the `OrderedMap` sketch above (HashMap<K, usize> index + Vec<(K, V)> entries).
+  O(1) removal uses swap_remove (last entry takes the hole); order-preserving
+  removal would need an O(n) shift plus re-indexing.
 
 COMMON PITFALLS
-- Assuming stable iteration order (it isn’t).
+- Assuming stable iteration order (it isn’t). For reproducible output, sort a snapshot:
+  `sorted_entries(&m)` (by key) or `sorted_by_value(&m)` (value, then key) — or use `BTreeMap`.
 - Double lookups for upsert instead of `entry`.
 - Holding references across operations that may rehash (keep borrows short).
 */
@@ -8,6 +8,14 @@ use hashmap_doc::{
     ex_building_collect_merge,
     ex_fn_signatures_and_passing,
     ex_common_patterns,
+    ex_ordered_map,
+    ex_case_insensitive_map,
+    ex_deterministic_iteration,
+    ex_histogram,
+    ex_top_k,
+    ex_merge_deep,
+    ex_trie,
+    ex_hashset_ops,
 };
 
 fn main() {
@@ -20,4 +28,12 @@ fn main() {
     ex_building_collect_merge();
     ex_fn_signatures_and_passing();
     ex_common_patterns();
+    ex_ordered_map();
+    ex_case_insensitive_map();
+    ex_deterministic_iteration();
+    ex_histogram();
+    ex_top_k();
+    ex_merge_deep();
+    ex_trie();
+    ex_hashset_ops();
 }
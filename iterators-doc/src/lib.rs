@@ -124,6 +124,505 @@ pub fn example_custom_iterator() {
 }
 
 
+/// Extension trait: custom lazy adapters available on every `Iterator`,
+/// the same way std's `map`/`filter` are default methods on `Iterator` itself.
+pub trait IteratorExt: Iterator + Sized {
+    /// Skip consecutive equal elements (`Vec::dedup` for any stream).
+    fn dedup(self) -> Dedup<Self>
+    where
+        Self::Item: PartialEq,
+    {
+        Dedup { iter: self, pending: None }
+    }
+
+    /// Put a clone of `sep` between consecutive items: `a, sep, b, sep, c`.
+    fn intersperse(self, sep: Self::Item) -> Intersperse<Self>
+    where
+        Self::Item: Clone,
+    {
+        Intersperse { iter: self.peekable(), sep, needs_sep: false }
+    }
+
+    /// Like `fold`, but yields every intermediate accumulator: `f(init, a), f(.., b), ..`.
+    /// `init` itself is *not* yielded, so the output has one item per input item.
+    fn running_fold<B, F>(self, init: B, f: F) -> RunningFold<Self, B, F>
+    where
+        B: Clone,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        RunningFold { iter: self, acc: Some(init), f }
+    }
+
+    /// Items strictly between the first `start` match and the next `end` match after it.
+    /// Neither marker is yielded. No end marker → runs to the end; no start marker → nothing.
+    fn between<S, E>(self, start: S, end: E) -> Between<Self, S, E>
+    where
+        S: FnMut(&Self::Item) -> bool,
+        E: FnMut(&Self::Item) -> bool,
+    {
+        Between { iter: self, start, end, state: BetweenState::BeforeStart }
+    }
+}
+
+impl<I: Iterator> IteratorExt for I {}
+
+/// Adapter returned by [`IteratorExt::dedup`].
+pub struct Dedup<I: Iterator> {
+    iter: I,
+    // First element of the *next* run, already pulled while skipping the current one.
+    pending: Option<I::Item>,
+}
+
+impl<I> Iterator for Dedup<I>
+where
+    I: Iterator,
+    I::Item: PartialEq,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.pending.take().or_else(|| self.iter.next())?;
+        // Swallow the rest of this run; keep the first different item for later.
+        for item in self.iter.by_ref() {
+            if item != current {
+                self.pending = Some(item);
+                break;
+            }
+        }
+        Some(current)
+    }
+}
+
+/// Adapter returned by [`IteratorExt::intersperse`].
+pub struct Intersperse<I: Iterator> {
+    // One-item lookahead: a separator is only emitted if another item follows,
+    // which is what keeps a trailing separator out.
+    iter: std::iter::Peekable<I>,
+    sep: I::Item,
+    needs_sep: bool, // true right after yielding an item
+}
+
+impl<I> Iterator for Intersperse<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.needs_sep && self.iter.peek().is_some() {
+            self.needs_sep = false;
+            Some(self.sep.clone())
+        } else {
+            self.needs_sep = true;
+            self.iter.next()
+        }
+    }
+}
+
+/// Adapter returned by [`IteratorExt::running_fold`].
+pub struct RunningFold<I, B, F> {
+    iter: I,
+    acc: Option<B>, // Option only so `f` can take the accumulator by value
+    f: F,
+}
+
+impl<I, B, F> Iterator for RunningFold<I, B, F>
+where
+    I: Iterator,
+    B: Clone,
+    F: FnMut(B, I::Item) -> B,
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let acc = (self.f)(self.acc.take()?, item);
+        self.acc = Some(acc.clone());
+        Some(acc)
+    }
+}
+
+/// Adapter returned by [`IteratorExt::between`].
+pub struct Between<I, S, E> {
+    iter: I,
+    start: S,
+    end: E,
+    state: BetweenState,
+}
+
+enum BetweenState {
+    BeforeStart, // skipping until `start` matches (the marker itself is dropped)
+    Inside,      // yielding until `end` matches
+    Done,        // end marker seen (or input ran out): never pull from `iter` again
+}
+
+impl<I, S, E> Iterator for Between<I, S, E>
+where
+    I: Iterator,
+    S: FnMut(&I::Item) -> bool,
+    E: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.state {
+                BetweenState::Done => return None,
+                BetweenState::BeforeStart => match self.iter.next() {
+                    Some(item) if (self.start)(&item) => self.state = BetweenState::Inside,
+                    Some(_) => {}
+                    None => self.state = BetweenState::Done,
+                },
+                BetweenState::Inside => match self.iter.next() {
+                    Some(item) if !(self.end)(&item) => return Some(item),
+                    _ => self.state = BetweenState::Done, // end marker, or exhausted
+                },
+            }
+        }
+    }
+}
+
+pub fn example_dedup_adapter() {
+    println!("\n== Example 6: Custom lazy adapter via extension trait (dedup) ==");
+    let input = [1, 1, 2, 2, 2, 3, 1];
+    let out: Vec<_> = input.iter().dedup().collect();
+    println!("{:?}.dedup() = {:?}", input, out);
+    assert_eq!(out, [&1, &2, &3, &1]); // non-adjacent 1 survives
+
+    let empty: Vec<i32> = Vec::new();
+    assert_eq!(empty.into_iter().dedup().count(), 0);
+
+    let same: Vec<_> = std::iter::repeat_n('z', 5).dedup().collect();
+    assert_eq!(same, ['z']);
+
+    // Lazy: works on streams with no backing Vec at all.
+    let words = "a a b b b a c c".split(' ').dedup().collect::<Vec<_>>().join(" ");
+    println!("words deduped = {:?}", words);
+}
+
+pub fn example_intersperse_adapter() {
+    println!("\n== Example 7: intersperse adapter (separator between items) ==");
+    // Called through the trait path: std has an unstable `Iterator::intersperse`
+    // with the same name, and method syntax would trip `unstable_name_collisions`.
+    let csv: String = IteratorExt::intersperse(["a", "b", "c"].into_iter(), ",").collect();
+    println!("joined = {:?}", csv);
+    assert_eq!(csv, "a,b,c"); // no trailing separator
+
+    let nums: Vec<i32> = IteratorExt::intersperse([1, 2, 3].into_iter(), 0).collect();
+    assert_eq!(nums, [1, 0, 2, 0, 3]);
+
+    let single: Vec<i32> = IteratorExt::intersperse(std::iter::once(7), 0).collect();
+    assert_eq!(single, [7]); // nothing to separate
+
+    let empty: Vec<i32> = IteratorExt::intersperse(std::iter::empty(), 0).collect();
+    assert!(empty.is_empty());
+}
+
+pub fn example_scan() {
+    println!("\n== Example 8: scan (running totals) and a running_fold adapter ==");
+    let nums = [3, 1, 4, 1, 5];
+
+    // std `scan`: the state lives in `&mut acc`; the closure decides what to yield
+    // (returning None would stop early). The initial state is not yielded.
+    let totals: Vec<i32> = nums
+        .iter()
+        .scan(0, |acc, &x| {
+            *acc += x;
+            Some(*acc)
+        })
+        .collect();
+    println!("scan running sums of {:?} = {:?}", nums, totals);
+    assert_eq!(totals, [3, 4, 8, 9, 14]);
+
+    // running_fold: fold-shaped closure (acc, item) -> acc, every accumulator yielded.
+    let sums: Vec<i32> = nums.iter().running_fold(0, |acc, &x| acc + x).collect();
+    assert_eq!(sums, totals);
+    assert_eq!(sums.len(), nums.len()); // init (0) is not emitted
+    assert_eq!(sums.last().copied(), Some(nums.iter().sum::<i32>()));
+
+    // Need the seed too? Chain it in front.
+    let with_seed: Vec<i32> = std::iter::once(0).chain(nums.iter().running_fold(0, |a, &x| a + x)).collect();
+    assert_eq!(with_seed, [0, 3, 4, 8, 9, 14]);
+
+    // Any accumulator type: running max, and prefixes of a string.
+    let maxes: Vec<i32> = nums.iter().running_fold(i32::MIN, |m, &x| m.max(x)).collect();
+    assert_eq!(maxes, [3, 3, 4, 4, 5]);
+    let prefixes: Vec<String> = "abc".chars().running_fold(String::new(), |mut s, c| { s.push(c); s }).collect();
+    println!("prefixes = {:?}", prefixes);
+    assert_eq!(prefixes, ["a", "ab", "abc"]);
+
+    // Empty input → nothing, not even the seed.
+    assert_eq!(std::iter::empty::<i32>().running_fold(0, |a, x| a + x).count(), 0);
+}
+
+/// Generator-style iterator: `f` gets the state by `&mut`, updates it, and returns
+/// the next item, or `None` to stop. (std spells this `iter::from_fn` + a captured
+/// variable; the separate `seed` keeps the state visible in the signature.)
+pub fn unfold<S, T>(seed: S, f: impl FnMut(&mut S) -> Option<T>) -> impl Iterator<Item = T> {
+    Unfold { state: seed, f }
+}
+
+struct Unfold<S, F> {
+    state: S,
+    f: F,
+}
+
+impl<S, T, F> Iterator for Unfold<S, F>
+where
+    F: FnMut(&mut S) -> Option<T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        (self.f)(&mut self.state)
+    }
+}
+
+pub fn example_unfold() {
+    println!("\n== Example 9: unfold (generator from mutable state): Collatz ==");
+    // State is the next number to emit; None once 1 has been emitted.
+    fn collatz(start: u64) -> impl Iterator<Item = u64> {
+        unfold(Some(start), |next| {
+            let n = (*next)?;
+            *next = match n {
+                1 => None,
+                n if n % 2 == 0 => Some(n / 2),
+                n => Some(3 * n + 1),
+            };
+            Some(n)
+        })
+    }
+
+    let six: Vec<u64> = collatz(6).collect();
+    println!("collatz(6) = {:?}", six);
+    assert_eq!(six, [6, 3, 10, 5, 16, 8, 4, 2, 1]);
+
+    let (len, peak, last) = collatz(27).fold((0, 0, 0), |(len, peak, _), n| (len + 1, peak.max(n), n));
+    println!("collatz(27): {} terms, peak {}", len, peak);
+    assert_eq!((len, peak, last), (112, 9232, 1)); // long detour, but it ends at 1
+    assert_eq!(collatz(1).collect::<Vec<_>>(), [1]);
+
+    // A closure that stops immediately gives an empty iterator.
+    let mut calls = 0;
+    let none: Vec<i32> = unfold((), |_| { calls += 1; None }).collect();
+    assert!(none.is_empty());
+    assert_eq!(calls, 1);
+
+    // Lazy like any adapter: unbounded state, bounded by `take`.
+    let pow2: Vec<u32> = unfold(1u32, |p| { let cur = *p; *p *= 2; Some(cur) }).take(5).collect();
+    assert_eq!(pow2, [1, 2, 4, 8, 16]);
+}
+
+/// Cartesian product: every `(a, b)` pair, `a`-major (like two nested `for` loops).
+/// `b` is collected into a `Vec` once and replayed for each `a`, so it's only iterated
+/// once (the iterator itself needn't be `Clone`, so e.g. `vec.drain(..)` works); each pair
+/// gets clones of both items. If either side is empty, so is the result.
+pub fn product<A: Clone, B: Clone>(
+    a: impl IntoIterator<Item = A>,
+    b: impl IntoIterator<Item = B>,
+) -> impl Iterator<Item = (A, B)> {
+    Product { a: a.into_iter(), cur: None, b: b.into_iter().collect(), j: 0 }
+}
+
+struct Product<I, A, B> {
+    a: I,
+    cur: Option<A>, // the `a` currently being paired
+    b: Vec<B>,
+    j: usize,       // next index into `b`
+}
+
+impl<I, A, B> Iterator for Product<I, A, B>
+where
+    I: Iterator<Item = A>,
+    A: Clone,
+    B: Clone,
+{
+    type Item = (A, B);
+
+    fn next(&mut self) -> Option<(A, B)> {
+        if self.b.is_empty() {
+            return None; // don't walk `a` (it may be endless) when nothing can pair with it
+        }
+        if self.j == self.b.len() || self.cur.is_none() {
+            self.cur = Some(self.a.next()?); // `a` exhausted → done
+            self.j = 0;
+        }
+        let pair = (self.cur.clone()?, self.b[self.j].clone()); // `cur` is always Some here
+        self.j += 1;
+        Some(pair)
+    }
+}
+
+pub fn example_product() {
+    println!("\n== Example 10: cartesian product of two iterables ==");
+    let pairs: Vec<(i32, char)> = product([1, 2, 3], ['x', 'y']).collect();
+    println!("product([1,2,3], ['x','y']) = {:?}", pairs);
+    assert_eq!(pairs, [(1, 'x'), (1, 'y'), (2, 'x'), (2, 'y'), (3, 'x'), (3, 'y')]);
+
+    // Count is len(a) * len(b), every pair exactly once.
+    let (a, b) = (vec!["s", "m", "l"], vec![10, 20, 30, 40]);
+    let all: Vec<_> = product(a.iter().copied(), b.iter().copied()).collect();
+    assert_eq!(all.len(), a.len() * b.len());
+    for x in &a {
+        for y in &b {
+            assert_eq!(all.iter().filter(|p| **p == (*x, *y)).count(), 1);
+        }
+    }
+    // Same as the nested-loop version with flat_map.
+    let nested: Vec<_> = a.iter().flat_map(|x| b.iter().map(move |y| (*x, *y))).collect();
+    assert_eq!(all, nested);
+
+    // Either side empty → empty product.
+    assert_eq!(product(Vec::<i32>::new(), [1, 2]).count(), 0);
+    assert_eq!(product([1, 2], Vec::<i32>::new()).count(), 0);
+    // ...even if the other side never ends.
+    assert_eq!(product(1.., Vec::<i32>::new()).count(), 0);
+
+    // `b` is replayed from the Vec; `a` stays lazy, so an endless `a` works with `take`.
+    let first: Vec<_> = product(1.., ["a", "b"]).take(3).collect();
+    assert_eq!(first, [(1, "a"), (1, "b"), (2, "a")]);
+
+    // `b` is consumed once, so a non-`Clone` iterator like `drain` is fine.
+    let mut pending = vec!['x', 'y'];
+    let drained: Vec<_> = product([0, 1], pending.drain(..)).collect();
+    assert_eq!(drained, [(0, 'x'), (0, 'y'), (1, 'x'), (1, 'y')]);
+    assert!(pending.is_empty());
+}
+
+pub fn example_between() {
+    println!("\n== Example 11: between(start, end) — a section between two markers ==");
+    let lines = ["intro", "BEGIN", "a", "b", "END", "c", "BEGIN", "d", "END"];
+    let section: Vec<_> = lines.iter().between(|l| **l == "BEGIN", |l| **l == "END").collect();
+    println!("between BEGIN/END = {:?}", section);
+    assert_eq!(section, [&"a", &"b"]); // markers excluded, only the first section
+
+    // Missing end marker: everything after the start, to the end of input.
+    let nums = [0, 1, 9, 2, 3, 4];
+    let tail: Vec<i32> = nums.into_iter().between(|&x| x == 9, |&x| x < 0).collect();
+    assert_eq!(tail, [2, 3, 4]);
+
+    // Missing start marker: nothing (and the end predicate is never consulted).
+    let mut end_checks = 0;
+    let none: Vec<i32> = nums.into_iter().between(|&x| x > 100, |_| { end_checks += 1; true }).collect();
+    assert!(none.is_empty());
+    assert_eq!(end_checks, 0);
+
+    // Markers back to back → empty section; the end is searched only *after* the start.
+    assert_eq!([1, 2, 3].into_iter().between(|&x| x == 1, |&x| x == 2).count(), 0);
+    assert_eq!([5, 1, 7, 5].into_iter().between(|&x| x == 1, |&x| x == 5).collect::<Vec<_>>(), [7]);
+
+    // Lazy and stops pulling at the end marker: works on an endless iterator.
+    let evens: Vec<u32> = (0..).between(|&x| x == 10, |&x| x == 20).filter(|x| x % 2 == 0).collect();
+    assert_eq!(evens, [12, 14, 16, 18]);
+}
+
+/// Splits `data` into consecutive sub-slices of at most `max_bytes` bytes (the last one may
+/// be shorter), e.g. for fixed-size network frames or upload parts. A thin wrapper over
+/// `slice::chunks` that states the contract up front.
+///
+/// Panics if `max_bytes == 0`: an empty iterator would silently drop all the data.
+pub fn byte_chunks(data: &[u8], max_bytes: usize) -> impl Iterator<Item = &[u8]> {
+    assert!(max_bytes > 0, "byte_chunks: max_bytes must be at least 1");
+    data.chunks(max_bytes)
+}
+
+/// Lines of `data`, split lazily on `\n` with the newline removed, like `str::lines` but on
+/// bytes (no UTF-8 check, `\r` is kept). A trailing `\n` does not produce an extra empty line.
+pub fn line_chunks(data: &[u8]) -> impl Iterator<Item = &[u8]> {
+    data.split_inclusive(|&b| b == b'\n').map(|line| line.strip_suffix(b"\n").unwrap_or(line))
+}
+
+pub fn example_byte_chunks() {
+    println!("\n== Example 12: byte_chunks / line_chunks over &[u8] ==");
+    let data = b"abcdefgh";
+    let exact: Vec<&[u8]> = byte_chunks(data, 4).collect();
+    println!("byte_chunks(\"abcdefgh\", 4) = {:?}", exact);
+    assert_eq!(exact, [b"abcd", b"efgh"]); // exact multiple: no short tail
+
+    let rem: Vec<&[u8]> = byte_chunks(data, 3).collect();
+    assert_eq!(rem, [&b"abc"[..], b"def", b"gh"]); // remainder in the last chunk
+    assert_eq!(byte_chunks(data, 100).collect::<Vec<_>>(), [&data[..]]);
+    assert_eq!(byte_chunks(data, 1).count(), data.len());
+    assert_eq!(byte_chunks(data, 3).map(<[u8]>::len).sum::<usize>(), data.len()); // nothing lost
+    assert_eq!(byte_chunks(b"", 4).count(), 0); // empty input → no chunks, not one empty chunk
+
+    // Zero is a caller bug: rejected with a message instead of looping or yielding nothing.
+    // (The panic message printed to stderr is expected.)
+    let err = std::panic::catch_unwind(|| byte_chunks(data, 0).count()).unwrap_err();
+    assert_eq!(err.downcast_ref::<&str>(), Some(&"byte_chunks: max_bytes must be at least 1"));
+
+    let text = b"GET / HTTP/1.1\r\nHost: x\r\n\nbody";
+    let lines: Vec<&[u8]> = line_chunks(text).collect();
+    println!("line_chunks = {:?}", lines.iter().map(|l| String::from_utf8_lossy(l)).collect::<Vec<_>>());
+    assert_eq!(lines, [&b"GET / HTTP/1.1\r"[..], b"Host: x\r", b"", b"body"]);
+    assert_eq!(line_chunks(b"a\nb\n").collect::<Vec<_>>(), [b"a", b"b"]); // no trailing ""
+    assert_eq!(line_chunks(b"\n").collect::<Vec<_>>(), [b""]);
+    assert_eq!(line_chunks(b"").count(), 0);
+
+    // Lazy: only the first line is scanned here.
+    assert_eq!(line_chunks(b"first\nsecond\nthird").next(), Some(&b"first"[..]));
+}
+
+pub fn example_collect_result() {
+    println!("\n== Example 13: collect into Result<Vec<_>, _> / Option<Vec<_>> ==");
+    // `Result<C, E>: FromIterator<Result<T, E>>` whenever `C: FromIterator<T>`: the first
+    // `Err` is returned and the rest of the iterator is never pulled.
+    let ok: Result<Vec<i32>, _> = ["1", "2", "3"].iter().map(|s| s.parse::<i32>()).collect();
+    println!("all ok      = {:?}", ok);
+    assert_eq!(ok, Ok(vec![1, 2, 3]));
+
+    // Count parse attempts to see exactly where the collect stops.
+    let input = ["4", "5", "x", "6", "y"];
+    let mut attempts = 0;
+    let res: Result<Vec<i32>, String> = input
+        .iter()
+        .map(|s| {
+            attempts += 1;
+            s.parse::<i32>().map_err(|e| format!("{s:?}: {e}"))
+        })
+        .collect();
+    println!("first error = {:?}", res);
+    assert_eq!(res, Err("\"x\": invalid digit found in string".to_string())); // "x", not "y"
+    assert_eq!(attempts, 3); // "6" and "y" never parsed
+
+    // Short-circuit even on an endless iterator, as long as an `Err` shows up.
+    let r: Result<Vec<u32>, u32> = (1..).map(|n| if n < 5 { Ok(n) } else { Err(n) }).collect();
+    assert_eq!(r, Err(5));
+
+    // Same for Option: any `None` → `None`, otherwise `Some(all values)`.
+    let chars = ['1', '7', '3'];
+    let digits: Option<Vec<u32>> = chars.iter().map(|c| c.to_digit(10)).collect();
+    assert_eq!(digits, Some(vec![1, 7, 3]));
+    let mut looked_at = 0;
+    let missing: Option<Vec<u32>> = ['1', 'z', '3']
+        .iter()
+        .map(|c| {
+            looked_at += 1;
+            c.to_digit(10)
+        })
+        .collect();
+    println!("with a None = {:?}", missing);
+    assert_eq!(missing, None);
+    assert_eq!(looked_at, 2);
+    // Empty input: nothing failed, so it's Some/Ok of an empty collection.
+    assert_eq!(std::iter::empty::<Option<u8>>().collect::<Option<Vec<_>>>(), Some(vec![]));
+
+    // Contrast: collecting into Vec<Result<_, _>> keeps every element, errors included —
+    // use it when you want to report all failures, then `partition` if needed.
+    let all: Vec<Result<i32, _>> = input.iter().map(|s| s.parse::<i32>()).collect();
+    assert_eq!(all.len(), input.len());
+    let (good, bad): (Vec<_>, Vec<_>) = all.into_iter().partition(Result::is_ok);
+    let good: Vec<i32> = good.into_iter().map(Result::unwrap).collect();
+    println!("keep all    → ok {:?}, {} errors", good, bad.len());
+    assert_eq!(good, [4, 5, 6]);
+    assert_eq!(bad.len(), 2);
+
+    // Other targets work too, e.g. summing with early exit: Option<u32>: Sum<Option<u32>>.
+    let total: Option<u32> = chars.iter().map(|c| c.to_digit(10)).sum();
+    assert_eq!(total, Some(11));
+}
+
 /*
 Docs-style notes:
 
@@ -149,6 +648,22 @@ Consuming adapters:
 Custom iterators:
 - Implement Iterator by writing your own next().
 - Once you have next(), you automatically get access to all the adapters.
+- For one-off generators, `unfold(seed, |state| ...)` (or std's `iter::from_fn` /
+  `iter::successors`) avoids writing the struct at all.
+- Combinators over two inputs (e.g. `product`) buffer whichever side must be replayed;
+  a plain iterator can only be walked once.
+
+Custom adapters (extension trait):
+- Define `trait IteratorExt: Iterator` with default methods returning wrapper structs.
+- Blanket impl `impl<I: Iterator> IteratorExt for I {}` makes them available everywhere.
+- The wrapper holds the inner iterator plus any buffered state (e.g. Dedup's pending item,
+  Intersperse's Peekable lookahead, RunningFold's accumulator).
+- Adapters with phases keep an explicit state enum (Between: BeforeStart → Inside → Done);
+  `Done` also makes them fused, so the inner iterator isn't touched after the end.
+- `scan` vs `running_fold`: scan mutates `&mut state` and may stop early (return None);
+  running_fold is fold-shaped `(acc, item) -> acc` and yields each acc (never the init).
+- Name clashes with unstable std methods (e.g. `intersperse`) trigger a lint; call via
+  `IteratorExt::intersperse(iter, sep)` until std stabilizes its version.
 
 Performance:
 - Iterators are zero-cost abstractions (monomorphized).
@@ -4,6 +4,14 @@ use iterators_doc::{
     example_adapters,
     example_consumers,
     example_custom_iterator,
+    example_dedup_adapter,
+    example_intersperse_adapter,
+    example_scan,
+    example_unfold,
+    example_product,
+    example_between,
+    example_byte_chunks,
+    example_collect_result,
 };
 
 fn main() {
@@ -12,4 +20,12 @@ fn main() {
     example_adapters();
     example_consumers();
     example_custom_iterator();
+    example_dedup_adapter();
+    example_intersperse_adapter();
+    example_scan();
+    example_unfold();
+    example_product();
+    example_between();
+    example_byte_chunks();
+    example_collect_result();
 }
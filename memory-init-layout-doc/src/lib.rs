@@ -1,16 +1,23 @@
 //! Memory layout & initialization in Rust — mini-docs + runnable examples
 //!
 //! Topics:
-//!  1) MaybeUninit<T>: uninitialized memory, manual init, *zeroing is not init*, safe patterns
-//!  2) ManuallyDrop<T>: suppress Drop (FFI buffers, unions); compare with mem::forget
-//!  3) Niche optimization & NonZero*: how `Option<NonZeroUsize>` is one word; `Option<&T>` too
+//!  1) MaybeUninit<T>: uninitialized memory, manual init, *zeroing is not init*, safe patterns,
+//!     `InlineVec<T, N>`, a fixed-capacity inline buffer, and over-aligned `Layout` allocations
+//!  2) ManuallyDrop<T>: suppress Drop (FFI buffers, unions, drop order); compare with mem::forget
+//!  3) Niche optimization & NonZero*: how `Option<NonZeroUsize>` is one word; `Option<&T>` too;
+//!     niche packing in custom enums, asserted as a layout regression guard
+//!  4) Pod-style byte serialization of `#[repr(C)]` structs: safe field-wise vs `transmute`
+//!  5) Array-of-structs vs struct-of-arrays: same data, different strides and cache behavior
 //!
 //! Run: `cargo run`
 
 use std::{
+    cell::RefCell,
     mem::{self, ManuallyDrop, MaybeUninit, size_of},
     num::{NonZeroU8, NonZeroUsize},
     ptr,
+    rc::Rc,
+    time::Instant,
 };
 
 /* ───────────────────────────── 1) MaybeUninit<T> ─────────────────────────────
@@ -91,6 +98,241 @@ pub fn ex_zeroing_note() {
   (Omitted here for brevity; see std docs for a drop guard pattern.)
 */
 
+/* ───────────── 1d) InlineVec<T, N>: a fixed-capacity stack buffer ─────────────
+The archetypal MaybeUninit type: `[MaybeUninit<T>; N]` + `len`, no heap allocation.
+Invariant: slots `0..len` are initialized, slots `len..N` are not. Every method keeps it:
+- `push` writes slot `len` then bumps `len`; when full it hands the value back (`Err`).
+- `pop` decrements `len` first, then reads the slot out (it is now logically uninit).
+- `Drop` drops exactly the initialized prefix — `MaybeUninit` itself never drops anything.
+*/
+
+pub struct InlineVec<T, const N: usize> {
+    buf: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> InlineVec<T, N> {
+    pub fn new() -> Self {
+        InlineVec { buf: [const { MaybeUninit::uninit() }; N], len: 0 }
+    }
+
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        if self.len == N {
+            return Err(value);
+        }
+        self.buf[self.len].write(value);
+        self.len += 1;
+        Ok(())
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // SAFETY: slot `len` was initialized; after the decrement nothing reads it again.
+        Some(unsafe { self.buf[self.len].assume_init_read() })
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: the first `len` slots are initialized; MaybeUninit<T> has T's layout.
+        unsafe { std::slice::from_raw_parts(self.buf.as_ptr().cast::<T>(), self.len) }
+    }
+
+    pub fn len(&self) -> usize { self.len }
+    pub fn is_empty(&self) -> bool { self.len == 0 }
+}
+
+impl<T, const N: usize> Default for InlineVec<T, N> {
+    fn default() -> Self { Self::new() }
+}
+
+impl<T, const N: usize> Drop for InlineVec<T, N> {
+    fn drop(&mut self) {
+        let init: *mut [T] = ptr::slice_from_raw_parts_mut(self.buf.as_mut_ptr().cast::<T>(), self.len);
+        // SAFETY: exactly the initialized prefix, dropped once; the rest is left alone.
+        unsafe { ptr::drop_in_place(init) };
+    }
+}
+
+pub fn ex_inline_vec() {
+    println!("\n== 1d) InlineVec<T, N>: MaybeUninit-backed fixed-capacity stack ==");
+    use std::cell::Cell;
+
+    let mut v: InlineVec<String, 3> = InlineVec::new();
+    for s in ["a", "b", "c"] {
+        v.push(s.to_string()).unwrap();
+    }
+    assert_eq!(v.push("d".to_string()), Err("d".to_string())); // full → value handed back
+    assert_eq!(v.as_slice(), ["a", "b", "c"]);
+    assert_eq!(v.pop().as_deref(), Some("c")); // LIFO
+    v.push("z".into()).unwrap();               // reuse the freed slot
+    println!("as_slice = {:?} (len {})", v.as_slice(), v.len());
+    assert_eq!(v.as_slice(), ["a", "b", "z"]);
+    while v.pop().is_some() {}
+    assert!(v.is_empty() && v.as_slice().is_empty() && v.pop().is_none());
+
+    // Partial fill: Drop must run exactly `len` destructors, not N.
+    struct Counted<'a>(&'a Cell<usize>);
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) { self.0.set(self.0.get() + 1); }
+    }
+    let drops = Cell::new(0);
+    {
+        let mut part: InlineVec<Counted, 8> = InlineVec::new();
+        for _ in 0..5 {
+            assert!(part.push(Counted(&drops)).is_ok());
+        }
+        drop(part.pop()); // 1 drop now
+        assert_eq!(drops.get(), 1);
+    } // 4 remaining initialized slots dropped, 4 uninit slots ignored
+    println!("drops after partial fill = {}", drops.get());
+    assert_eq!(drops.get(), 5);
+
+    // Zero capacity is fine too.
+    let mut none: InlineVec<u8, 0> = InlineVec::new();
+    assert_eq!(none.push(1), Err(1));
+}
+
+/* ───────────── 1e) Zeroed allocation vs element-by-element init ─────────────
+`vec![0u8; n]` is special-cased: zero is a valid `u8`, so it allocates with
+`alloc_zeroed` (→ `calloc`). Large calloc requests get fresh pages from the OS, which
+are already zero, so no bytes are written up front — the cost shows up later as page
+faults when the memory is first touched. Writing each slot through `MaybeUninit` (the
+`spare_capacity_mut` + `set_len` pattern) always touches every byte immediately.
+Zeroing is *sound* only when all-zero bytes are a valid value of the type (integers,
+floats, raw pointers, `Option<NonNull<T>>`…); `String`, `&T`, `NonZero*` are not.
+Timings below are illustrative only (single run, no warm-up) — not a benchmark.
+*/
+
+pub fn ex_zeroed_init_perf() {
+    println!("\n== 1e) vec![0; N] (zeroed alloc) vs MaybeUninit per-element init ==");
+    const N: usize = 16 * 1024 * 1024;
+
+    let t = Instant::now();
+    let zeroed: Vec<u8> = std::hint::black_box(vec![0u8; N]);
+    let t_zeroed = t.elapsed();
+
+    let t = Instant::now();
+    let mut written: Vec<u8> = Vec::with_capacity(N);
+    for slot in &mut written.spare_capacity_mut()[..N] {
+        slot.write(0);
+    }
+    // SAFETY: the first N slots were just initialized, and N <= capacity.
+    unsafe { written.set_len(N) };
+    let written = std::hint::black_box(written);
+    let t_written = t.elapsed();
+
+    println!("{:<29}: {:?}", format!("vec![0u8; {N}]"), t_zeroed);
+    println!("{:<29}: {:?}", format!("MaybeUninit writes × {N}"), t_written);
+    println!("(first pass over the zeroed buffer pays its deferred page faults)");
+
+    assert_eq!((zeroed.len(), written.len()), (N, N));
+    assert!(zeroed.iter().all(|&b| b == 0));
+    assert_eq!(zeroed, written);
+
+    // Same idea for a POD struct: all-zero is a valid value, so `zeroed()` is sound.
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    #[repr(C)]
+    struct Sample { t: u64, v: f32, ch: u16 }
+    let z: [Sample; 4] = unsafe { MaybeUninit::zeroed().assume_init() };
+    assert_eq!(z, [Sample { t: 0, v: 0.0, ch: 0 }; 4]);
+}
+
+/* ───────────── 1f) Over-aligned heap buffers with Layout ─────────────
+`Vec<u8>` only promises `align_of::<u8>() == 1`. For SIMD loads or cache-line-sized
+slots you want e.g. 64-byte alignment; ask the allocator directly with a `Layout`.
+Invariants (each one is UB if broken):
+- `size > 0` — `alloc` with a zero-size layout is UB; `align` must be a power of two
+  (`Layout::from_size_align` checks this and the size-overflow case for us),
+- a null return means OOM → `handle_alloc_error`, never a usable pointer,
+- fresh memory is *uninitialized*: write each byte (`ptr::write`/`write_bytes`) before reading,
+- `dealloc` gets the *same* pointer and the *same* layout, exactly once.
+`AlignedBuf` owns the allocation, so `Drop` performs that single `dealloc`.
+*/
+
+struct AlignedBuf {
+    ptr: ptr::NonNull<u8>,
+    layout: std::alloc::Layout,
+}
+
+impl AlignedBuf {
+    /// `len` bytes aligned to `align`, every byte initialized to `fill`.
+    fn new(len: usize, align: usize, fill: u8) -> Self {
+        let layout = std::alloc::Layout::from_size_align(len, align).expect("align must be a power of two");
+        assert!(layout.size() > 0, "zero-size allocations are not allowed");
+        // SAFETY: the layout has non-zero size.
+        let raw = unsafe { std::alloc::alloc(layout) };
+        let Some(ptr) = ptr::NonNull::new(raw) else { std::alloc::handle_alloc_error(layout) };
+        // SAFETY: `ptr` is valid for `len` writes; after this every byte is initialized.
+        unsafe { ptr.as_ptr().write_bytes(fill, len) };
+        AlignedBuf { ptr, layout }
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        // SAFETY: `len` initialized bytes, uniquely borrowed through `&mut self`.
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.layout.size()) }
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        // SAFETY: as above, shared borrow.
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.layout.size()) }
+    }
+}
+
+impl Drop for AlignedBuf {
+    fn drop(&mut self) {
+        // SAFETY: allocated in `new` with exactly this layout; `drop` runs once.
+        unsafe { std::alloc::dealloc(self.ptr.as_ptr(), self.layout) };
+    }
+}
+
+pub fn ex_aligned_alloc() {
+    println!("\n== 1f) 64-byte aligned allocation via Layout ==");
+    const ALIGN: usize = 64;
+
+    // Raw version first, step by step.
+    let layout = std::alloc::Layout::from_size_align(256, ALIGN).unwrap();
+    // SAFETY: non-zero size; null is checked before any use.
+    let p = unsafe { std::alloc::alloc(layout) };
+    if p.is_null() {
+        std::alloc::handle_alloc_error(layout);
+    }
+    assert_eq!(p as usize % ALIGN, 0);
+    for i in 0..layout.size() {
+        // SAFETY: in bounds; `write` doesn't read (or drop) the uninitialized old byte.
+        unsafe { p.add(i).write(i as u8) };
+    }
+    // SAFETY: all 256 bytes were written above.
+    let bytes = unsafe { std::slice::from_raw_parts(p, layout.size()) };
+    assert!(bytes.iter().enumerate().all(|(i, &b)| b == i as u8));
+    println!("raw alloc at {p:p}: {} bytes, addr % {ALIGN} = {}", bytes.len(), p as usize % ALIGN);
+    // SAFETY: same pointer, same layout, once; `bytes` is not used after this.
+    unsafe { std::alloc::dealloc(p, layout) };
+
+    // Owned wrapper: the same steps, with `dealloc` tied to Drop.
+    for len in [1, 63, 64, 1000] {
+        let mut buf = AlignedBuf::new(len, ALIGN, 0xAB);
+        assert_eq!(buf.ptr.as_ptr() as usize % ALIGN, 0, "len {len}");
+        assert!(buf.as_slice().iter().all(|&b| b == 0xAB));
+        for (i, b) in buf.as_mut_slice().iter_mut().enumerate() {
+            *b = (i % 251) as u8;
+        }
+        assert!(buf.as_slice().iter().enumerate().all(|(i, &b)| b == (i % 251) as u8));
+    } // each `buf` freed here, once
+
+    // Typed view: a 64-byte aligned block holds 16 f32 lanes for one AVX-512 load.
+    let mut lanes = AlignedBuf::new(16 * size_of::<f32>(), ALIGN, 0);
+    let f: &mut [f32] = unsafe {
+        // SAFETY: 64 initialized bytes, aligned ≥ align_of::<f32>(), all-zero is a valid f32.
+        std::slice::from_raw_parts_mut(lanes.as_mut_slice().as_mut_ptr().cast::<f32>(), 16)
+    };
+    f.iter_mut().enumerate().for_each(|(i, x)| *x = i as f32 * 0.5);
+    assert_eq!(f.iter().sum::<f32>(), 60.0);
+
+    assert!(std::alloc::Layout::from_size_align(64, 48).is_err()); // 48 is not a power of two
+}
+
 /* ───────────────────────────── 2) ManuallyDrop<T> ─────────────────────────────
 Wrap a value to *suppress automatic Drop*. You can later:
 - extract it (consuming) via `ManuallyDrop::into_inner` (no Drop called on the wrapper),
@@ -146,6 +388,84 @@ pub fn ex_manuallydrop_ffi_style() {
     // Drop occurs once, here, when owned_box goes out of scope.
 }
 
+/* ───────────── 2c) Vec::from_raw_parts: (ptr, len, cap) round-trip ─────────────
+A `Vec<T>` is just (ptr, len, cap). You can take it apart (e.g. to hand a buffer to C)
+and later rebuild it — but only if *all* of these hold:
+- `ptr` came from a `Vec<T>` (same allocator, same `T` size/alignment),
+- `cap` is exactly the capacity it was allocated with,
+- `len <= cap` and the first `len` elements are initialized,
+- the parts are turned back into a Vec **once** (twice = double free).
+`Vec::into_raw_parts` is still unstable, so the stable recipe is `ManuallyDrop::new(v)`
+(the std docs prefer it over `mem::forget`: no window where `v` is both owned and leaked).
+*/
+
+pub fn ex_vec_from_raw_parts() {
+    println!("\n== 2c) Vec -> (ptr, len, cap) -> Vec ==");
+    let mut v: Vec<u32> = Vec::with_capacity(8);
+    v.extend([10, 20, 30]);
+
+    // Decompose: suppress Vec's Drop, then read the three parts.
+    let mut v = ManuallyDrop::new(v);
+    let (ptr, len, cap): (*mut u32, usize, usize) = (v.as_mut_ptr(), v.len(), v.capacity());
+    println!("parts: ptr={ptr:p}, len={len}, cap={cap}");
+
+    // While decomposed we own raw memory; e.g. write through the pointer (len stays 3).
+    // SAFETY: index 1 < len, so it is in bounds and initialized.
+    unsafe { *ptr.add(1) += 1 };
+
+    // Rebuild exactly once, with the original cap; this Vec now owns (and frees) the buffer.
+    // SAFETY: parts come from a Vec<u32> with this exact len/cap; `v` is never used again.
+    let rebuilt: Vec<u32> = unsafe { Vec::from_raw_parts(ptr, len, cap) };
+    println!("rebuilt = {:?} (cap={})", rebuilt, rebuilt.capacity());
+    assert_eq!(rebuilt, [10, 21, 30]);
+    assert_eq!((rebuilt.as_ptr(), rebuilt.capacity()), (ptr as *const u32, cap));
+    // `rebuilt` drops here → single free.
+
+    // ❌ let again = unsafe { Vec::from_raw_parts(ptr, len, cap) }; // double free (UB)
+    // ❌ Vec::from_raw_parts(ptr, len, len)                         // wrong cap → UB on free
+}
+
+/* ───────────── 2d) ManuallyDrop for custom drop order ─────────────
+Fields normally drop in *declaration* order, after the struct's own `Drop::drop`.
+Wrapping fields in `ManuallyDrop` switches that off, so `Drop::drop` can drop them in
+whatever order it needs (e.g. a handle that must die before the context it borrows from).
+Each field must be dropped exactly once, and never used afterwards.
+*/
+
+struct Tracked {
+    name: &'static str,
+    log: Rc<RefCell<Vec<&'static str>>>,
+}
+impl Drop for Tracked {
+    fn drop(&mut self) { self.log.borrow_mut().push(self.name); }
+}
+
+struct Ordered {
+    first: ManuallyDrop<Tracked>,  // declared first…
+    second: ManuallyDrop<Tracked>, // …but dropped first below
+}
+impl Drop for Ordered {
+    fn drop(&mut self) {
+        // SAFETY: each field is dropped once, here, and `self` is not touched afterwards.
+        unsafe {
+            ManuallyDrop::drop(&mut self.second);
+            ManuallyDrop::drop(&mut self.first);
+        }
+    }
+}
+
+pub fn ex_drop_order() {
+    println!("\n== 2d) ManuallyDrop: reverse-of-declaration drop order ==");
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let track = |name| ManuallyDrop::new(Tracked { name, log: Rc::clone(&log) });
+
+    let o = Ordered { first: track("first"), second: track("second") };
+    drop(o);
+    println!("drop order: {:?}", log.borrow());
+    assert_eq!(*log.borrow(), ["second", "first"]); // chosen order, each exactly once
+    assert_eq!(Rc::strong_count(&log), 1);         // both clones released → nothing leaked
+}
+
 /* ───────────── 3) Niche optimization & NonZero* (and pointers) ─────────────
 A “niche” is a bit-pattern that a type never uses. The compiler can pack an `Option<T>`
 into the same size as `T` by using the niche to encode `None`.
@@ -187,6 +507,219 @@ pub fn ex_nonzero_api() {
     println!("ids: {} -> {}", a.get(), b.get());
 }
 
+/* ───────────── 3c) Niche packing in your own enums (layout regression guard) ─────────────
+The same trick applies to any enum with *one* payload-carrying variant: the dataless
+variants are stored in bit patterns the payload can never have. How many such variants fit
+depends on how many niche values the payload has:
+- `NonZeroU32` has exactly one (0) → one extra variant is free, a second one costs a tag.
+- `bool` uses 2 of 256 byte values → 254 niches, so even `Option<Option<bool>>` is 1 byte.
+- A plain `u32` has none → the tag needs its own space (padded to 8 bytes).
+The assertions double as a guard: if a refactor (say `NonZeroU32` → `u32`) silently grows
+a type that's stored by the million, this fails loudly.
+*/
+
+pub fn ex_enum_niche_packing() {
+    use std::num::NonZeroU32;
+    println!("\n== 3c) Enum niche packing ==");
+
+    #[allow(dead_code)]
+    enum Id { Valid(NonZeroU32), Invalid }              // Invalid ↔ 0
+    #[allow(dead_code)]
+    enum IdOrState { Valid(NonZeroU32), Invalid, Expired } // only one niche for two variants
+    #[allow(dead_code)]
+    enum RawId { Valid(u32), Invalid }                  // no niche at all
+
+    let word = size_of::<usize>();
+    let rows: [(&str, usize, usize); 10] = [
+        ("Option<Box<u64>>", size_of::<Option<Box<u64>>>(), word),
+        ("Option<&u8>", size_of::<Option<&u8>>(), word),
+        ("Option<NonZeroU32>", size_of::<Option<NonZeroU32>>(), 4),
+        ("Result<(), NonZeroU32>", size_of::<Result<(), NonZeroU32>>(), 4),
+        ("Id { Valid(NonZeroU32), Invalid }", size_of::<Id>(), 4),
+        ("IdOrState (+ Expired)", size_of::<IdOrState>(), 8),
+        ("RawId { Valid(u32), Invalid }", size_of::<RawId>(), 8),
+        ("Option<Option<NonZeroU32>>", size_of::<Option<Option<NonZeroU32>>>(), 8),
+        ("Option<bool>", size_of::<Option<bool>>(), 1),
+        ("Option<Option<bool>>", size_of::<Option<Option<bool>>>(), 1),
+    ];
+    for (name, actual, expected) in rows {
+        println!("{name:<36} = {actual}");
+        assert_eq!(actual, expected, "layout changed for {name}");
+    }
+
+    // The niche value really is 0: `Id::Invalid` occupies the bytes a zero id would have.
+    // SAFETY: `Id` is 4 bytes with no padding (asserted above), any 4 bytes form a valid u32.
+    let raw: u32 = unsafe { mem::transmute::<Id, u32>(Id::Invalid) };
+    assert_eq!(raw, 0);
+    let five: u32 = unsafe { mem::transmute::<Id, u32>(Id::Valid(NonZeroU32::new(5).unwrap())) };
+    assert_eq!(five, 5); // no tag bits: the payload is stored as-is
+    // (Observing the niche like this is for demonstration; the encoding of a non-`repr`
+    // enum isn't a stable guarantee — only `Option<NonZero*>`/`Option<&T>` etc. are.)
+}
+
+
+/* ───────────── 4) Pod-style byte serialization for fixed structs ─────────────
+“Plain old data”: a `#[repr(C)]` struct of integers has a fixed field order and no
+invalid bit patterns, so it can round-trip through bytes.
+
+Two ways to do it:
+- Field-wise `to_le_bytes` / `from_le_bytes`: safe, endian-explicit, no layout assumptions.
+- `transmute` / pointer casts: zero-copy, but only sound if
+  * the struct is `#[repr(C)]` with no padding (padding bytes are uninit → UB to read),
+  * every bit pattern is valid for every field (true for u32, false for bool/char/enums),
+  * you never *reference* misaligned bytes as `&Header` (a `&[u8]` is only 1-aligned);
+    copy out by value (`transmute` of an array, `ptr::read_unaligned`) instead,
+  * you accept native endianness (bytes differ between LE and BE machines).
+Crates like `bytemuck` / `zerocopy` encode these rules as traits (`Pod`, `FromBytes`).
+*/
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Header {
+    pub magic: u32,
+    pub len: u32,
+}
+
+const HEADER_SIZE: usize = size_of::<Header>(); // 8, no padding between two u32s
+
+impl Header {
+    pub fn to_bytes(&self) -> [u8; HEADER_SIZE] {
+        let mut out = [0u8; HEADER_SIZE];
+        out[..4].copy_from_slice(&self.magic.to_le_bytes());
+        out[4..].copy_from_slice(&self.len.to_le_bytes());
+        out
+    }
+
+    /// Safe decode: `None` if `bytes` is too short (never reads past the end).
+    pub fn from_bytes(bytes: &[u8]) -> Option<Header> {
+        let bytes: &[u8; HEADER_SIZE] = bytes.get(..HEADER_SIZE)?.try_into().ok()?;
+        Some(Header {
+            magic: u32::from_le_bytes(bytes[..4].try_into().ok()?),
+            len: u32::from_le_bytes(bytes[4..].try_into().ok()?),
+        })
+    }
+
+    /// Zero-copy flavored decode via `transmute` (native endian!). Length-guarded.
+    pub fn from_bytes_transmute(bytes: &[u8]) -> Option<Header> {
+        let arr: [u8; HEADER_SIZE] = bytes.get(..HEADER_SIZE)?.try_into().ok()?;
+        // SAFETY: Header is repr(C), 8 bytes, no padding, and any bit pattern is a
+        // valid u32. Transmuting the array *by value* sidesteps alignment; casting
+        // `bytes.as_ptr()` to `&Header` would not (it may be misaligned).
+        Some(unsafe { mem::transmute::<[u8; HEADER_SIZE], Header>(arr) })
+    }
+}
+
+pub fn ex_pod_roundtrip() {
+    println!("\n== 4) Pod-style byte serialization (repr(C) Header) ==");
+    let h = Header { magic: 0xCAFE_BABE, len: 42 };
+
+    let bytes = h.to_bytes();
+    println!("bytes = {:02X?}", bytes);
+    assert_eq!(bytes, [0xBE, 0xBA, 0xFE, 0xCA, 42, 0, 0, 0]); // little-endian, byte-exact
+
+    let back = Header::from_bytes(&bytes).expect("8 bytes is enough");
+    println!("decoded = {:?}", back);
+    assert_eq!(back, h);
+
+    // Truncated input is rejected instead of reading out of bounds.
+    assert_eq!(Header::from_bytes(&bytes[..7]), None);
+    assert_eq!(Header::from_bytes_transmute(&bytes[..3]), None);
+    println!("truncated (7 bytes) -> {:?}", Header::from_bytes(&bytes[..7]));
+
+    // Extra trailing bytes are fine: only the prefix is read.
+    let mut framed = bytes.to_vec();
+    framed.extend_from_slice(b"payload");
+    assert_eq!(Header::from_bytes(&framed), Some(h));
+
+    // transmute uses native endianness, so it only matches on little-endian targets.
+    let native = Header::from_bytes_transmute(&bytes).unwrap();
+    println!("transmute decode (native endian) = {:?}", native);
+    if cfg!(target_endian = "little") {
+        assert_eq!(native, h);
+    }
+}
+
+/* ───────────── 5) Array-of-structs vs struct-of-arrays ─────────────
+Same logical data, two layouts:
+- AoS `Vec<Point3>`: `x y z x y z …` — one allocation, a point's fields sit together.
+  Great when you touch whole points (transform, copy one out).
+- SoA `{ xs, ys, zs }`: `x x x … | y y y … | z z z …` — three allocations, each field dense.
+  Great when a pass reads one field for *all* points (sum of x, filter by z): every cache line
+  loaded is 100% useful data, and the loop is a straight `f32` slice that auto-vectorizes.
+  In AoS that pass strides over 12 bytes to use 4, so 2/3 of each cache line is wasted.
+Heap bytes are identical (no padding in `Point3`); SoA pays only two extra `Vec` headers.
+*/
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Points3Soa {
+    pub xs: Vec<f32>,
+    pub ys: Vec<f32>,
+    pub zs: Vec<f32>,
+}
+
+impl Points3Soa {
+    pub fn from_aos(points: &[Point3]) -> Self {
+        Points3Soa {
+            xs: points.iter().map(|p| p.x).collect(),
+            ys: points.iter().map(|p| p.y).collect(),
+            zs: points.iter().map(|p| p.z).collect(),
+        }
+    }
+
+    pub fn to_aos(&self) -> Vec<Point3> {
+        assert!(self.xs.len() == self.ys.len() && self.ys.len() == self.zs.len(), "ragged SoA");
+        self.xs.iter().zip(&self.ys).zip(&self.zs).map(|((&x, &y), &z)| Point3 { x, y, z }).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.xs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.xs.is_empty()
+    }
+}
+
+pub fn ex_soa_vs_aos() {
+    println!("\n== 5) AoS Vec<Point3> vs SoA {{ xs, ys, zs }} ==");
+    let aos: Vec<Point3> = (0..1000).map(|i| i as f32).map(|f| Point3 { x: f, y: f * 2.0, z: -f }).collect();
+    let soa = Points3Soa::from_aos(&aos);
+
+    assert_eq!(size_of::<Point3>(), 12); // three f32s, no padding
+    println!("size_of::<Point3>()       = {}", size_of::<Point3>());
+    println!("AoS: header {} B + heap {} B", size_of::<Vec<Point3>>(), aos.len() * size_of::<Point3>());
+    println!("SoA: header {} B + heap {} B", size_of::<Points3Soa>(), 3 * soa.len() * size_of::<f32>());
+    assert_eq!(aos.len() * size_of::<Point3>(), 3 * soa.len() * size_of::<f32>());
+
+    // Distance between consecutive x values: the stride a "sum all x" loop walks.
+    let aos_stride = (&aos[1].x as *const f32 as usize) - (&aos[0].x as *const f32 as usize);
+    let soa_stride = (&soa.xs[1] as *const f32 as usize) - (&soa.xs[0] as *const f32 as usize);
+    println!("x-to-x stride: AoS {aos_stride} B, SoA {soa_stride} B");
+    assert_eq!((aos_stride, soa_stride), (12, 4));
+
+    // Same logical data either way.
+    assert_eq!(soa.len(), aos.len());
+    for (i, p) in aos.iter().enumerate() {
+        assert_eq!((p.x, p.y, p.z), (soa.xs[i], soa.ys[i], soa.zs[i]));
+    }
+    let sum_aos: f32 = aos.iter().map(|p| p.x).sum();
+    let sum_soa: f32 = soa.xs.iter().sum(); // dense slice: SIMD-friendly
+    assert_eq!(sum_aos, sum_soa);
+
+    // Round-trip, including the empty case.
+    assert_eq!(soa.to_aos(), aos);
+    assert_eq!(Points3Soa::from_aos(&soa.to_aos()), soa);
+    assert!(Points3Soa::from_aos(&[]).is_empty());
+    assert!(Points3Soa::default().to_aos().is_empty());
+}
 
 /* ───────────────────────────── Docs-style notes ─────────────────────────────
 
@@ -211,6 +744,8 @@ MANUALLYDROP<T>
   * Extract: `let v = unsafe { ManuallyDrop::into_inner(m) };` (consumes `m`)
   * Drop now: `unsafe { ManuallyDrop::drop(&mut m) }`
 - `mem::forget(value)` *leaks* the value forever (never drops). Prefer `ManuallyDrop` when you still want control.
+- Vec round-trip: `ManuallyDrop::new(v)` → `(as_mut_ptr, len, capacity)` → `Vec::from_raw_parts` once,
+  with the exact original capacity and the same allocator.
 
 NICHE OPTIMIZATION (size wins)
 - `Option<&T>` / `Option<Box<T>>` / `Option<NonZero*>` are the same size as their non-Option counterparts.
@@ -219,6 +754,11 @@ NICHE OPTIMIZATION (size wins)
   to guarantee the one-word layout and document the invariant.
 - This optimization is automatic. No unsafe needed.
 
+POD SERIALIZATION
+- Prefer field-wise `to_le_bytes`/`from_le_bytes`: explicit endianness, no unsafe.
+- `transmute`/casts need: `#[repr(C)]`, no padding, all bit patterns valid, no misaligned refs.
+- Always length-check input (`bytes.get(..N)?`) before decoding; return `None` on short input.
+
 PITFALLS
 - UB magnets: calling `assume_init` too early; zero-initializing non-zeroable types; reading uninit bytes.
 - Mixing partial init with panics: if constructing a collection element-by-element, use a guard to drop
@@ -2,19 +2,35 @@ use memory_init_layout_doc::{
     ex_maybeuninit_array,
     ex_maybeuninit_out_param,
     ex_zeroing_note,
+    ex_inline_vec,
+    ex_zeroed_init_perf,
+    ex_aligned_alloc,
     ex_manuallydrop_basics,
     ex_manuallydrop_ffi_style,
+    ex_vec_from_raw_parts,
+    ex_drop_order,
     ex_niche_sizes,
     ex_nonzero_api,
+    ex_enum_niche_packing,
+    ex_pod_roundtrip,
+    ex_soa_vs_aos,
 };
 
 fn main() {
     ex_maybeuninit_array();
     ex_maybeuninit_out_param();
     ex_zeroing_note();
+    ex_inline_vec();
+    ex_zeroed_init_perf();
+    ex_aligned_alloc();
     ex_manuallydrop_basics();
     ex_manuallydrop_ffi_style();
+    ex_vec_from_raw_parts();
+    ex_drop_order();
     ex_niche_sizes();
     ex_nonzero_api();
+    ex_enum_niche_packing();
+    ex_pod_roundtrip();
+    ex_soa_vs_aos();
     println!("\n== Cheatsheet in comments below ==");
 }
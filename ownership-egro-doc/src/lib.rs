@@ -1,9 +1,12 @@
 //! Ownership Ergonomics in Rust — mini-docs + runnable examples
 //!
 //! Topics:
-//!  1) `Cow<'a, T>` (copy-on-write) for “borrow most, own occasionally”; `ToOwned`
-//!  2) Borrowing helpers: `Borrow`, `AsRef`, `Into`/`From` — flexible, zero-copy-ish APIs
-//!  3) Guard types: `MutexGuard`, `RwLockReadGuard`/`RwLockWriteGuard`, `Ref`/`RefMut`
+//!  1) `Cow<'a, T>` (copy-on-write) for “borrow most, own occasionally”; `ToOwned`;
+//!     in practice: zero-copy CSV fields, decompress-or-passthrough byte buffers;
+//!     `MaybeOwned<'a, T>`, the same idea for any `T: Clone`
+//!  2) Borrowing helpers: `Borrow`, `AsRef`, `Into`/`From` — flexible, zero-copy-ish APIs;
+//!     in practice: a `String`-keyed cache queried with `&str`, an `Into<String>` constructor
+//!  3) Guard types: `MutexGuard`, `RwLockReadGuard`/`RwLockWriteGuard`, `Ref`/`RefMut`, `ScopeGuard`
 //!
 //! Run: `cargo run`
 
@@ -11,6 +14,7 @@ use std::{
     borrow::{Borrow, Cow, ToOwned},
     cell::{RefCell, Ref, RefMut},
     collections::HashMap,
+    io,
     path::{Path, PathBuf},
     sync::{Arc, Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard},
     thread,
@@ -81,6 +85,248 @@ pub fn ex_cow_slice() {
     println!("b: {:?} (owned? {})", b, matches!(b, Cow::Owned(_)));
 }
 
+/* ───────────────── 1c) ToOwned: borrowed → owned, generically ─────────────────
+`Clone` only goes `&T -> T`, so it can't turn `&str` into `String` (str isn't Sized).
+`ToOwned` generalizes it with an associated `Owned` type:
+- `str: ToOwned<Owned = String>`, `[T]: ToOwned<Owned = Vec<T>>`, `Path: ToOwned<Owned = PathBuf>`
+- every `T: Clone` gets `ToOwned<Owned = T>` for free
+This is exactly the bound `Cow<'a, B>` needs to produce its `Owned` variant.
+*/
+
+fn owned_copy<B: ToOwned + ?Sized>(x: &B) -> B::Owned {
+    x.to_owned() // deep copy into the owned counterpart
+}
+
+pub fn ex_to_owned_generic() {
+    println!("\n== 1c) ToOwned generic deep copy ==");
+    let s: &str = "borrowed";
+    let mut owned_s: String = owned_copy(s);
+    assert_eq!(owned_s, s);
+    owned_s.push_str(" -> owned"); // owned: free to mutate, source untouched
+    println!("str    -> String : {:?} (source {:?})", owned_s, s);
+
+    let xs: &[i32] = &[1, 2, 3];
+    let mut owned_xs: Vec<i32> = owned_copy(xs);
+    assert_eq!(owned_xs, xs);
+    owned_xs.push(4);
+    println!("[i32]  -> Vec    : {:?} (source {:?})", owned_xs, xs);
+
+    let p: &Path = Path::new("src");
+    let mut owned_p: PathBuf = owned_copy(p);
+    assert_eq!(owned_p, p);
+    owned_p.push("lib.rs");
+    println!("Path   -> PathBuf: {} (source {})", owned_p.display(), p.display());
+
+    assert_eq!((owned_s.as_str(), owned_xs.len()), ("borrowed -> owned", 4));
+    assert_eq!(owned_p, Path::new("src/lib.rs"));
+}
+
+/* ───────────── 1d) Cow in practice: zero-copy CSV fields ─────────────
+Most CSV fields are plain (`abc`) or quoted without escapes (`"a,b"`): both can be
+returned as a *slice of the input line*. Only fields with escaped quotes (`"say ""hi"""`)
+need a new `String` with `""` → `"`. `Cow` lets one return type cover both.
+*/
+
+fn parse_field<'a>(raw: &'a str) -> Cow<'a, str> {
+    let inner = match raw.strip_prefix('"').and_then(|r| r.strip_suffix('"')) {
+        Some(quoted) => quoted,
+        None => return Cow::Borrowed(raw), // unquoted: taken verbatim
+    };
+    if inner.contains("\"\"") {
+        Cow::Owned(inner.replace("\"\"", "\"")) // allocate only when unescaping
+    } else {
+        Cow::Borrowed(inner) // still a slice of `raw`
+    }
+}
+
+// Split on commas that are not inside quotes, then parse each field.
+fn split_csv_line(line: &str) -> Vec<Cow<'_, str>> {
+    let mut fields = Vec::new();
+    let (mut start, mut in_quotes) = (0, false);
+    for (i, ch) in line.char_indices() {
+        match ch {
+            '"' => in_quotes = !in_quotes, // `""` toggles twice → stays inside
+            ',' if !in_quotes => {
+                fields.push(parse_field(&line[start..i]));
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    fields.push(parse_field(&line[start..]));
+    fields
+}
+
+pub fn ex_cow_csv_fields() {
+    println!("\n== 1d) Cow<'a, str> for zero-copy CSV fields ==");
+    let plain = parse_field("alpha");
+    let quoted = parse_field("\"a, b\"");
+    let escaped = parse_field("\"say \"\"hi\"\"\"");
+    println!("plain={:?}, quoted={:?}, escaped={:?}", plain, quoted, escaped);
+    assert!(matches!(plain, Cow::Borrowed("alpha")));
+    assert!(matches!(quoted, Cow::Borrowed("a, b")));
+    assert!(matches!(&escaped, Cow::Owned(s) if s == "say \"hi\""));
+    assert!(matches!(parse_field("\"\""), Cow::Borrowed(""))); // empty quoted field
+
+    let line = "42,\"Doe, Jane\",\"5\"\" tall\",ok";
+    let fields = split_csv_line(line);
+    let owned = fields.iter().filter(|f| matches!(f, Cow::Owned(_))).count();
+    println!("{line} -> {:?} ({owned} allocation(s))", fields);
+    assert_eq!(fields, ["42", "Doe, Jane", "5\" tall", "ok"]);
+    assert_eq!(owned, 1); // only the field with an escaped quote allocated
+}
+
+/* ───────────── 1e) Cow<[u8]>: decompress only when needed ─────────────
+A reader that accepts "maybe compressed" payloads: plain data is passed through as a
+borrow of the input (zero copies); only compressed data needs a new buffer.
+Toy format so there are no deps: `RLE_MAGIC` followed by `(count, byte)` pairs.
+A payload that carries the magic but isn't valid pairs is passed through untouched.
+*/
+
+const RLE_MAGIC: &[u8] = b"RLE\0";
+
+fn maybe_decompress<'a>(data: &'a [u8]) -> Cow<'a, [u8]> {
+    let Some(body) = data.strip_prefix(RLE_MAGIC) else {
+        return Cow::Borrowed(data); // not compressed: hand the input back as-is
+    };
+    if body.len() % 2 != 0 || body.chunks(2).any(|p| p[0] == 0) {
+        return Cow::Borrowed(data); // malformed: don't guess
+    }
+    let mut out = Vec::with_capacity(body.chunks(2).map(|p| p[0] as usize).sum());
+    for pair in body.chunks(2) {
+        out.extend(std::iter::repeat_n(pair[1], pair[0] as usize));
+    }
+    Cow::Owned(out)
+}
+
+// Encoder for the demo: runs are capped at 255 so the count fits in a byte.
+fn rle_compress(data: &[u8]) -> Vec<u8> {
+    let mut out = RLE_MAGIC.to_vec();
+    for run in data.chunk_by(|a, b| a == b) {
+        for part in run.chunks(255) {
+            out.extend([part.len() as u8, part[0]]);
+        }
+    }
+    out
+}
+
+pub fn ex_cow_maybe_decompress() {
+    println!("\n== 1e) Cow<'a, [u8]>: decompress or pass through ==");
+    let plain: &[u8] = b"hello, world";
+    let out = maybe_decompress(plain);
+    assert!(matches!(out, Cow::Borrowed(b) if std::ptr::eq(b, plain))); // same bytes, no copy
+
+    let original = [b'a'; 300].iter().chain(b"bbc").copied().collect::<Vec<u8>>();
+    let packed = rle_compress(&original);
+    println!("{} bytes packed into {} (incl. {}-byte magic)", original.len(), packed.len(), RLE_MAGIC.len());
+    assert_eq!(packed, b"RLE\0\xff\x61\x2d\x61\x02\x62\x01\x63"); // 255 + 45 'a', 2 'b', 1 'c'
+    let out = maybe_decompress(&packed);
+    assert!(matches!(&out, Cow::Owned(v) if *v == original));
+
+    // Callers see `&[u8]` either way (Cow derefs), and can keep it borrowed.
+    let describe = |c: &Cow<[u8]>| if matches!(c, Cow::Borrowed(_)) { "borrowed" } else { "owned" };
+    println!("plain -> {}, packed -> {}", describe(&maybe_decompress(plain)), describe(&out));
+    assert_eq!(out.len(), 303);
+
+    // Edge cases: empty input, magic with empty body, magic with a dangling byte.
+    assert!(matches!(maybe_decompress(b""), Cow::Borrowed(b"")));
+    assert!(matches!(maybe_decompress(RLE_MAGIC), Cow::Owned(v) if v.is_empty()));
+    assert!(matches!(maybe_decompress(b"RLE\0\x03"), Cow::Borrowed(_)));
+}
+
+/* ───────────── 1f) MaybeOwned<'a, T>: Cow for any `T: Clone` ─────────────
+`Cow<'a, B>` pairs a *borrowed* type with its *owned* counterpart (`str`/`String`), via
+`ToOwned`. When both are the same sized type (a config struct, a `Vec` you hold by `&Vec`),
+the enum is just "a `&T` or a `T`", and `Clone` is all that's needed to go from one to
+the other. (`Cow<'a, T>` with `T: Clone` works too — this spells out the mechanism.)
+- `to_mut` clones on the *first* call only, then hands out the owned value.
+- `into_owned` clones only if it's still borrowed.
+- `Deref` lets callers read through either variant.
+*/
+
+pub enum MaybeOwned<'a, T: Clone> {
+    Borrowed(&'a T),
+    Owned(T),
+}
+
+impl<T: Clone> MaybeOwned<'_, T> {
+    pub fn to_mut(&mut self) -> &mut T {
+        if let MaybeOwned::Borrowed(b) = *self {
+            *self = MaybeOwned::Owned(b.clone()); // the one and only clone
+        }
+        match self {
+            MaybeOwned::Owned(t) => t,
+            MaybeOwned::Borrowed(_) => unreachable!("converted above"),
+        }
+    }
+
+    pub fn into_owned(self) -> T {
+        match self {
+            MaybeOwned::Borrowed(b) => b.clone(),
+            MaybeOwned::Owned(t) => t,
+        }
+    }
+
+    pub fn is_owned(&self) -> bool {
+        matches!(self, MaybeOwned::Owned(_))
+    }
+}
+
+impl<T: Clone> std::ops::Deref for MaybeOwned<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match self {
+            MaybeOwned::Borrowed(b) => b,
+            MaybeOwned::Owned(t) => t,
+        }
+    }
+}
+
+pub fn ex_maybe_owned() {
+    println!("\n== 1f) MaybeOwned<'a, T>: clone-on-mutate for any T: Clone ==");
+    use std::cell::Cell;
+    thread_local!(static CLONES: Cell<usize> = const { Cell::new(0) });
+
+    #[derive(Debug, PartialEq)]
+    struct Settings {
+        retries: u32,
+        tags: Vec<String>,
+    }
+    impl Clone for Settings {
+        fn clone(&self) -> Self {
+            CLONES.with(|c| c.set(c.get() + 1)); // count every deep copy
+            Settings { retries: self.retries, tags: self.tags.clone() }
+        }
+    }
+    let clones = || CLONES.with(Cell::get);
+    CLONES.with(|c| c.set(0)); // per-thread and static: reset so reruns start from zero
+
+    let defaults = Settings { retries: 3, tags: vec!["base".into()] };
+
+    // Read-only use never clones.
+    let view = MaybeOwned::Borrowed(&defaults);
+    assert_eq!(view.retries, 3); // via Deref
+    assert_eq!(clones(), 0);
+
+    // First to_mut clones, later ones reuse the owned copy.
+    let mut custom = MaybeOwned::Borrowed(&defaults);
+    custom.to_mut().retries = 5;
+    assert_eq!(clones(), 1);
+    assert!(custom.is_owned());
+    custom.to_mut().tags.push("fast".into());
+    custom.to_mut().retries += 1;
+    assert_eq!(clones(), 1); // still one
+    println!("custom = {:?} after {} clone(s); defaults = {:?}", *custom, clones(), defaults);
+    assert_eq!(defaults, Settings { retries: 3, tags: vec!["base".into()] }); // untouched
+
+    // into_owned: free for Owned, one clone for Borrowed.
+    let owned = custom.into_owned();
+    assert_eq!((owned.retries, owned.tags.len(), clones()), (6, 2, 1));
+    let copy = view.into_owned();
+    assert_eq!((copy.retries, copy.tags.len(), clones()), (3, 1, 2));
+}
+
 /* ─────────────────── 2) Borrow, AsRef, Into / From ───────────────────
 Designing flexible APIs that accept many input types without copying.
 
@@ -134,6 +380,173 @@ pub fn ex_borrow_asref_into() {
     println!("needs_owned(String) = {}", needs_owned(String::from("yo")));
 }
 
+/* ─────────────── 2b) AsRef<str> for "anything stringy" parameters ───────────────
+A logger only *reads* the message, so `S: AsRef<str>` accepts `&str`, `String`, `&String`,
+`Cow<str>`, `Box<str>`, … and just borrows a `&str` out of it — no allocation, and an owned
+`String` passed in is simply dropped after the call.
+
+The `Into<String>` version looks just as flexible but is worse here:
+    fn log_line_owned<S: Into<String>>(level: &str, msg: S) {
+        let msg: String = msg.into(); // allocates + copies for every &str / &String / Cow::Borrowed
+        println!("[{level}] {msg}");
+    }
+Use `Into<String>` only when the callee keeps the string (stores it in a struct, etc.).
+*/
+
+fn write_log_line<W: io::Write, S: AsRef<str>>(out: &mut W, level: &str, msg: S) -> io::Result<()> {
+    writeln!(out, "[{level}] {}", msg.as_ref())
+}
+
+fn log_line<S: AsRef<str>>(level: &str, msg: S) {
+    write_log_line(&mut io::stdout().lock(), level, msg).expect("stdout closed");
+}
+
+pub fn ex_asref_str_logging() {
+    println!("\n== 2b) AsRef<str> logging: accept anything stringy ==");
+    let owned = String::from("from String");
+    let borrowed: Cow<str> = Cow::Borrowed("from Cow::Borrowed");
+    let cow_owned: Cow<str> = Cow::Owned(format!("from Cow::Owned #{}", 2));
+
+    log_line("INFO", "from &str");
+    log_line("INFO", &owned);          // &String
+    log_line("WARN", borrowed.clone()); // Cow<str> implements AsRef<str> → compiles as-is
+    log_line("WARN", &cow_owned);      // &Cow<str> too
+    log_line("INFO", owned);           // String, moved in and dropped
+
+    // Same formatting, captured into a buffer so it can be checked.
+    let mut buf: Vec<u8> = Vec::new();
+    let s = String::from("string");
+    write_log_line(&mut buf, "INFO", "str").unwrap();
+    write_log_line(&mut buf, "INFO", &s).unwrap();
+    write_log_line(&mut buf, "INFO", s).unwrap();
+    write_log_line(&mut buf, "DEBUG", borrowed).unwrap();
+    write_log_line(&mut buf, "DEBUG", cow_owned).unwrap();
+    write_log_line(&mut buf, "ERROR", Box::<str>::from("boxed")).unwrap();
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        "[INFO] str\n[INFO] string\n[INFO] string\n\
+         [DEBUG] from Cow::Borrowed\n[DEBUG] from Cow::Owned #2\n[ERROR] boxed\n"
+    );
+}
+
+/* ─────────────── 2c) Borrow<str> in practice: a String-keyed cache ───────────────
+Keys are owned (`String`) because the map must keep them; lookups are borrowed (`&str`)
+because the caller usually only has a slice — a literal, a substring of a line, a token.
+`HashMap::get<Q>` accepts any `&Q` with `String: Borrow<Q>`, and `String: Borrow<str>`,
+so `get(&str)` hashes and compares the slice directly; no temporary `String` is built.
+That only works because `Borrow` promises `hash(s) == hash(s.borrow())` and same `Eq`.
+*/
+
+pub struct StringCache<V> {
+    map: HashMap<String, V>,
+}
+
+impl<V> StringCache<V> {
+    pub fn new() -> Self {
+        StringCache { map: HashMap::new() }
+    }
+
+    /// Takes ownership of the key: the map stores it. Returns the replaced value, if any.
+    pub fn insert(&mut self, key: String, value: V) -> Option<V> {
+        self.map.insert(key, value)
+    }
+
+    /// Borrowed lookup: `&str` in, via `String: Borrow<str>` — the caller never allocates.
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.map.get(key)
+    }
+}
+
+impl<V> Default for StringCache<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn ex_string_cache() {
+    println!("\n== 2c) StringCache: String keys, &str lookups (Borrow<str>) ==");
+    let mut cache = StringCache::new();
+    cache.insert("alpha".to_string(), 1);
+    cache.insert(String::from("beta"), 2);
+    assert_eq!(cache.insert("alpha".into(), 10), Some(1)); // same key, replaced
+
+    // Lookups with plain slices: a literal, and a substring of a larger buffer.
+    let line = "GET beta HTTP/1.1";
+    let token: &str = &line[4..8]; // borrowed out of `line`, nothing owned
+    println!("get(\"alpha\") = {:?}, get({token:?}) = {:?}", cache.get("alpha"), cache.get(token));
+    assert_eq!(cache.get("alpha"), Some(&10));
+    assert_eq!(cache.get(token), Some(&2));
+    // An owned String still works, via deref to &str.
+    assert_eq!(cache.get(&String::from("beta")), Some(&2));
+
+    // Missing keys (and near misses: case and whitespace count) → None.
+    assert_eq!(cache.get("gamma"), None);
+    assert_eq!(cache.get("Alpha"), None);
+    assert_eq!(cache.get("beta "), None);
+    assert_eq!(cache.get(""), None);
+
+    // What makes it sound: the String and its borrowed &str hash identically.
+    use std::hash::BuildHasher;
+    let hasher = std::collections::hash_map::RandomState::new();
+    let owned = String::from("alpha");
+    let borrowed: &str = owned.borrow();
+    assert_eq!(hasher.hash_one(&owned), hasher.hash_one(borrowed));
+
+    let empty: StringCache<()> = StringCache::default();
+    assert!(empty.get("anything").is_none());
+}
+
+/* ─────────────── 2d) Into<String> when the callee keeps the value ───────────────
+The mirror image of 2b: `Email` *stores* its string, so it needs a `String` no matter what.
+`S: Into<String>` lets callers pass `&str` (one allocation, unavoidable) or a `String`
+(moved in: the same heap buffer, zero copies). Taking `&str` instead would force a copy
+even when the caller already had an owned `String` it was done with.
+Validation failure hands back a message; the rejected input is dropped.
+*/
+
+#[derive(Debug, PartialEq)]
+pub struct Email(String);
+
+impl Email {
+    pub fn new<S: Into<String>>(s: S) -> Result<Email, String> {
+        let s: String = s.into(); // &str → allocates; String → moved, no-op
+        match s.split_once('@') {
+            Some((user, domain)) if !user.is_empty() && !domain.is_empty() && !domain.contains('@') => Ok(Email(s)),
+            _ => Err(format!("invalid email {s:?}: expected exactly one '@' with text on both sides")),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+pub fn ex_into_constructor() {
+    println!("\n== 2d) Email::new(impl Into<String>): own flexibly, validate once ==");
+    // From &str: converted (and allocated) inside `new`.
+    let a = Email::new("ada@example.com").unwrap();
+    assert_eq!(a.as_str(), "ada@example.com");
+
+    // From String: moved in. Same heap buffer → nothing was cloned.
+    let owned = format!("{}@{}", "grace", "example.org");
+    let (ptr, cap) = (owned.as_ptr(), owned.capacity());
+    let b = Email::new(owned).unwrap(); // `owned` is moved; using it afterwards won't compile
+    println!("{:?} from &str, {:?} from String (buffer reused: {})", a, b, b.as_str().as_ptr() == ptr);
+    assert_eq!(b.as_str().as_ptr(), ptr);
+    assert_eq!(b.0.capacity(), cap);
+
+    // Other stringy types work through their `From` impls.
+    assert!(Email::new(Cow::Borrowed("x@y")).is_ok());
+    assert!(Email::new(Box::<str>::from("x@y")).is_ok());
+
+    // Validation failures.
+    for bad in ["no-at-sign", "@example.com", "user@", "a@b@c", ""] {
+        let err = Email::new(bad).unwrap_err();
+        assert!(err.contains("invalid email"), "{bad:?}");
+    }
+    println!("rejected: {}", Email::new("no-at-sign").unwrap_err());
+}
+
 /* ────────────────────────── 3) Guard types ──────────────────────────
 "Guards" are values that *own a lock or a borrow* and implement `Deref`/`DerefMut`
 to access the protected inner value. When the guard is dropped, the lock/borrow is released.
@@ -257,6 +670,67 @@ pub fn ex_guard_pitfall_demo() {
     }
 }
 
+/* ─────────────────────────── 3e) Scope guards (defer) ───────────────────────────
+The same RAII idea as `MutexGuard`, but for *any* cleanup: a value whose `Drop` runs a
+closure. It fires on normal scope exit, on early `return`/`?`, and during panic unwinding.
+`dismiss()` disarms it (e.g. "roll back unless we reach the commit point").
+*/
+
+pub struct ScopeGuard<F: FnMut()> {
+    f: F,
+    armed: bool,
+}
+
+pub fn defer<F: FnMut()>(f: F) -> ScopeGuard<F> {
+    ScopeGuard { f, armed: true }
+}
+
+impl<F: FnMut()> ScopeGuard<F> {
+    /// Cancel the deferred action; consuming `self` drops the guard right away.
+    pub fn dismiss(mut self) {
+        self.armed = false;
+    }
+}
+
+impl<F: FnMut()> Drop for ScopeGuard<F> {
+    fn drop(&mut self) {
+        if self.armed {
+            (self.f)();
+        }
+    }
+}
+
+pub fn ex_scope_guard() {
+    println!("\n== 3e) ScopeGuard / defer (run cleanup on drop) ==");
+    use std::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+    let cleanups = AtomicUsize::new(0);
+
+    // Normal scope exit.
+    {
+        let _g = defer(|| { cleanups.fetch_add(1, SeqCst); });
+        println!("doing work...");
+    } // cleanup runs here
+    assert_eq!(cleanups.load(SeqCst), 1);
+
+    // Panic: the guard is dropped during unwinding, so cleanup still runs.
+    // (The panic message printed to stderr is expected.)
+    let result = std::panic::catch_unwind(|| {
+        let _g = defer(|| { cleanups.fetch_add(1, SeqCst); });
+        panic!("boom inside guarded scope");
+    });
+    assert!(result.is_err());
+    assert_eq!(cleanups.load(SeqCst), 2);
+
+    // Dismissed: the "rollback" never happens.
+    {
+        let g = defer(|| { cleanups.fetch_add(100, SeqCst); });
+        println!("commit point reached; dismissing rollback");
+        g.dismiss();
+    }
+    assert_eq!(cleanups.load(SeqCst), 2);
+    println!("cleanups run = {}", cleanups.load(SeqCst));
+}
+
 /* ─────────────────────────────────── main ─────────────────────────────────── */
 
 
@@ -268,6 +742,14 @@ COW<'a, T>
 - Use when your function *often* returns a borrow but *sometimes* needs to allocate or modify.
 - Key methods: `Cow::Borrowed(_)/Owned(_)`, `into_owned()`, `to_mut()`, `is_borrowed()`/`is_owned()`.
 
+- Parsing: return `Cow::Borrowed(slice_of_input)` on the fast path, `Cow::Owned` only when you
+  must rewrite (e.g. CSV `""` → `"`); callers can't tell the difference except in allocation count.
+
+TOOWNED
+- `trait ToOwned { type Owned: Borrow<Self>; fn to_owned(&self) -> Self::Owned; }`
+- `Clone` for unsized/borrowed forms: `str → String`, `[T] → Vec<T>`, `Path → PathBuf`, `OsStr → OsString`.
+- Generic deep copy: `fn owned_copy<B: ToOwned + ?Sized>(x: &B) -> B::Owned`.
+
 BORROW / ASREF / INTO (and FROM)
 - `AsRef<T>`: zero-cost ref conversion (borrow-in, borrow-out). Great for read-only params:
   `fn f<P: AsRef<Path>>(p: P) { let p: &Path = p.as_ref(); }`
@@ -302,4 +784,5 @@ CHEATSHEET
 - Borrow lookup:         `map.get::<str>("key")` because `String: Borrow<str>`
 - Own if needed:         `fn g<S: Into<String>>(s: S) { let s = s.into(); }`
 - Mutex “with” pattern:  `fn with_lock<T,R,F:FnOnce(&mut T)->R>(m:&Mutex<T>, f:F)->R`
+- Scope guard:           `let _g = defer(|| cleanup());` … `g.dismiss()` to cancel
 */
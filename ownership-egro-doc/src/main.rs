@@ -1,20 +1,36 @@
 use ownership_egro_doc::{
     ex_cow_str,
     ex_cow_slice,
+    ex_to_owned_generic,
+    ex_cow_csv_fields,
+    ex_cow_maybe_decompress,
+    ex_maybe_owned,
     ex_borrow_asref_into,
+    ex_asref_str_logging,
+    ex_string_cache,
+    ex_into_constructor,
     ex_mutex_guard_lifetimes,
     ex_rwlock_guards,
     ex_refcell_guards_runtime,
     ex_guard_pitfall_demo,
+    ex_scope_guard,
 };
 
 fn main() {
     ex_cow_str();
     ex_cow_slice();
+    ex_to_owned_generic();
+    ex_cow_csv_fields();
+    ex_cow_maybe_decompress();
+    ex_maybe_owned();
     ex_borrow_asref_into();
+    ex_asref_str_logging();
+    ex_string_cache();
+    ex_into_constructor();
     ex_mutex_guard_lifetimes();
     ex_rwlock_guards();
     ex_refcell_guards_runtime();
     ex_guard_pitfall_demo();
+    ex_scope_guard();
     println!("\n== Cheatsheet in comments below ==");
 }
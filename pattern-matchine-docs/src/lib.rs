@@ -2,6 +2,13 @@
 //!
 //! Patterns let you concisely decompose and test data shapes in `match`, `let`, `if let`,
 //! `while let`, function params, and more. They’re exhaustive by default in `match`.
+//!
+//! Builds on stable. `--features nightly` (nightly toolchain) adds the unstable `box` pattern.
+
+#![cfg_attr(feature = "nightly", feature(box_patterns))]
+
+#[cfg(feature = "nightly")]
+mod nightly;
 
 #[derive(Debug)]
 struct User {
@@ -110,11 +117,42 @@ pub fn ex_references_boxes() {
         &val => println!("ref other: {val}"),
     }
 
+    // Stable Rust has no pattern that looks *through* a Box; deref it first.
+    // `*b` on a Box can move the value out (Box is special-cased for this).
     let b = Box::new(String::from("hello"));
-    match b {
-        // `box` pattern moves out of the Box (value owned here)
-        box s => println!("boxed string moved out: {s}"),
+    let s: String = *b; // Box consumed, String owned here
+    println!("boxed string moved out: {s}");
+    assert_eq!(s, "hello");
+
+    // Match the inner value by reference: `as_ref()` (or `&*b`) gives `&Shape`.
+    let shape = Box::new(Shape::Rect { w: 2.0, h: 3.0 });
+    let area = match shape.as_ref() {
+        Shape::Circle { r } => std::f64::consts::PI * r * r,
+        Shape::Rect { w, h } => w * h, // w, h: &f64
+        Shape::Unit => 0.0,
+    };
+    println!("boxed rect area = {area}");
+    assert_eq!(area, 6.0);
+
+    // Or match on `*shape` directly; Copy fields bind by value, the Box stays usable.
+    match *shape {
+        Shape::Rect { w, .. } => assert_eq!(w, 2.0),
+        _ => unreachable!(),
     }
+
+    // Box nested in an Option: `as_deref()` turns `&Option<Box<T>>` into `Option<&T>`.
+    let maybe: Option<Box<i32>> = Some(Box::new(7));
+    match maybe.as_deref() {
+        Some(&n) if n > 5 => println!("boxed option > 5: {n}"),
+        Some(n) => println!("boxed option: {n}"),
+        None => println!("no box"),
+    }
+    assert_eq!(maybe.as_deref(), Some(&7));
+
+    // Nightly only: the `box` pattern destructures through the Box in one step.
+    // Build with `cargo +nightly run --features nightly`.
+    #[cfg(feature = "nightly")]
+    nightly::ex_box_pattern();
 }
 
 pub fn ex_while_let() {
@@ -127,6 +165,49 @@ pub fn ex_while_let() {
     }
 }
 
+pub fn ex_while_let_drain() {
+    println!("\n== while let: consuming collections ==");
+    use std::collections::{BTreeMap, HashMap};
+
+    // Vec as a stack: `pop()` returns Option<T>; the loop ends at `None` (empty).
+    // Unlike `for x in &stack`, the body may push more work while looping.
+    let mut stack = vec![("a", 0), ("b", 0), ("c", 0)];
+    let mut order = vec![];
+    while let Some((name, depth)) = stack.pop() { // tuple pattern straight from the Option
+        order.push(name);
+        if name == "b" && depth == 0 {
+            stack.push(("b.1", 1)); // discovered work goes on top → handled next
+            stack.push(("b.2", 1));
+        }
+    }
+    println!("LIFO order: {order:?}");
+    assert_eq!(order, ["c", "b", "b.2", "b.1", "a"]);
+    assert!(stack.is_empty());
+
+    // HashMap, one entry at a time: copy the key out (`Some(&k)`) so the borrow from
+    // `keys()` ends before `remove` needs `&mut map`. Iteration order is unspecified.
+    let mut inventory = HashMap::from([("bolts", 40), ("nuts", 25), ("gears", 3)]);
+    let mut removed = vec![];
+    while let Some(&item) = inventory.keys().next() {
+        let qty = inventory.remove(item).unwrap();
+        removed.push((item, qty));
+    }
+    removed.sort();
+    assert_eq!(removed, [("bolts", 40), ("gears", 3), ("nuts", 25)]);
+    assert!(inventory.is_empty());
+    // (`for (k, v) in map.drain()` does the same in one pass; the loop above is the
+    //  pattern for when each removal may decide what else to remove or insert.)
+
+    // Ordered collections have a pop that fits `while let` directly.
+    let mut queue = BTreeMap::from([(3, "low"), (1, "urgent"), (2, "normal")]);
+    let mut served = vec![];
+    while let Some((prio, job)) = queue.pop_first() {
+        served.push((prio, job));
+    }
+    assert_eq!(served, [(1, "urgent"), (2, "normal"), (3, "low")]);
+    assert!(queue.is_empty());
+}
+
 pub fn ex_matches_macro() {
     println!("\n== matches! macro ==");
     let s = Shape::Circle { r: 2.0 };
@@ -184,6 +265,172 @@ pub fn ex_function_param_patterns() {
 }
 
 
+pub fn ex_ref_bindings() {
+    println!("\n== ref / ref mut vs & / &mut patterns ==");
+    let mut data = vec![1, 2, 3];
+    let v: &mut Vec<i32> = &mut data;
+
+    // Modern: scrutinee is a reference, so match ergonomics kicks in and
+    // plain bindings become `&mut i32` automatically (default binding mode).
+    if let [first, .., last] = v.as_mut_slice() {
+        *first *= 10; // first: &mut i32
+        *last *= 10;  // last:  &mut i32
+    }
+    println!("after ergonomics: {:?}", v);
+    assert_eq!(*v, [10, 2, 30]);
+
+    // Old style: scrutinee is a *place* (`*v`), not a reference, so ergonomics
+    // does NOT apply. A plain binding would try to move out; `ref mut` borrows.
+    if let [_, ref mut middle, ..] = **v {
+        *middle += 100; // middle: &mut i32
+    }
+    println!("after ref mut:    {:?}", v);
+    assert_eq!(*v, [10, 102, 30]);
+
+    // Classic case that still needs `ref`: match an owned value without moving it.
+    let label = Some(String::from("kept"));
+    if let Some(ref s) = label {
+        println!("borrowed {s}"); // `Some(s)` would move the String
+    }
+    println!("label still usable: {:?}", label);
+
+    // `&` pattern is the opposite of `ref`: it *peels* a reference to copy out.
+    let nums = [5, 6];
+    let &[a, b] = &nums;   // a, b: i32 (copied out through the &)
+    let [ref c, _] = nums; // c: &i32 (borrowed into the array)
+    println!("& peels: a={a}, b={b}; ref borrows: c={c}");
+    assert_eq!((a, b, *c), (5, 6, 5));
+
+    // Edition 2024: writing `ref`/`ref mut`/`&` *inside* a pattern that is
+    // already under ergonomics (scrutinee is a reference) is an error, e.g.
+    //   match &label { Some(ref s) => .. } // error: binding modifiers may only be
+    //                                      // written when default mode is `move`
+}
+
+/// A public enum that promises to grow: `#[non_exhaustive]` lets us add variants
+/// later without a breaking change, because *other crates* must keep a `_` arm.
+///
+/// Downstream (any other crate, including doctests and this package's `main.rs`),
+/// covering every current variant is still not enough:
+///
+/// ```compile_fail,E0004
+/// use pattern_matchine_docs::HttpMethod;
+/// fn is_safe(m: HttpMethod) -> bool {
+///     match m {
+///         HttpMethod::Get | HttpMethod::Head => true,
+///         HttpMethod::Post | HttpMethod::Delete => false,
+///     } // error[E0004]: non-exhaustive patterns: `_` not covered
+/// }
+/// ```
+///
+/// With the wildcard it compiles, and keeps compiling when a variant is added:
+///
+/// ```
+/// use pattern_matchine_docs::HttpMethod;
+/// fn is_safe(m: HttpMethod) -> bool {
+///     match m {
+///         HttpMethod::Get | HttpMethod::Head => true,
+///         HttpMethod::Post | HttpMethod::Delete => false,
+///         _ => false, // unknown future method: be conservative
+///     }
+/// }
+/// assert!(is_safe(HttpMethod::Get));
+/// assert!(!is_safe(HttpMethod::Delete));
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HttpMethod {
+    Get,
+    Head,
+    Post,
+    Delete,
+}
+
+pub fn ex_non_exhaustive() {
+    println!("\n== #[non_exhaustive] enums ==");
+    // Inside the defining crate the attribute has no effect on matching: this
+    // match is exhaustive as written, and a trailing `_` would be flagged as an
+    // unreachable pattern. The `_` requirement only applies across crate
+    // boundaries (see the doc examples on `HttpMethod`).
+    fn describe(m: HttpMethod) -> &'static str {
+        match m {
+            HttpMethod::Get => "read",
+            HttpMethod::Head => "read headers",
+            HttpMethod::Post => "create",
+            HttpMethod::Delete => "remove",
+        }
+    }
+
+    let all = [HttpMethod::Get, HttpMethod::Head, HttpMethod::Post, HttpMethod::Delete];
+    for m in all {
+        println!("{m:?} -> {}", describe(m));
+    }
+    assert_eq!(all.map(describe), ["read", "read headers", "create", "remove"]);
+    // Why it matters: if v2 adds `HttpMethod::Patch`, every downstream match already
+    // has a `_` arm, so upgrading is not a compile error for users of this crate.
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharClass {
+    Digit(u32),              // ASCII '0'..='9', with its value
+    Letter { upper: bool },  // ASCII letters only
+    Whitespace,
+    Punctuation,
+    OtherAlphanumeric,       // non-ASCII letters/digits: 'é', 'ß', '٣', '漢'
+    Other,
+}
+
+/// Ranges + `@` bindings handle the ASCII cases; guards catch what a range can't
+/// express (Unicode properties). Arms are tried top to bottom, and a guard arm is
+/// never "unreachable" to the compiler — so the order is on us.
+pub fn classify(c: char) -> CharClass {
+    match c {
+        d @ '0'..='9' => CharClass::Digit(d as u32 - '0' as u32),
+        l @ ('a'..='z' | 'A'..='Z') => CharClass::Letter { upper: l.is_ascii_uppercase() },
+        c if c.is_whitespace() => CharClass::Whitespace, // ' ', '\t', '\n', U+00A0, U+3000, ...
+        '!'..='/' | ':'..='@' | '['..='`' | '{'..='~' => CharClass::Punctuation, // ASCII gaps between the ranges above
+        c if c.is_alphanumeric() => CharClass::OtherAlphanumeric,
+        _ => CharClass::Other,
+    }
+}
+
+pub fn ex_char_classification() {
+    println!("\n== char ranges + guards → classification ==");
+    use CharClass::*;
+    let cases = [
+        ('0', Digit(0)), ('7', Digit(7)), ('9', Digit(9)),
+        ('a', Letter { upper: false }), ('Z', Letter { upper: true }),
+        (' ', Whitespace), ('\t', Whitespace), ('\n', Whitespace), ('\u{a0}', Whitespace), ('\u{3000}', Whitespace),
+        ('!', Punctuation), ('/', Punctuation), (':', Punctuation), ('@', Punctuation),
+        ('[', Punctuation), ('`', Punctuation), ('{', Punctuation), ('~', Punctuation),
+        ('é', OtherAlphanumeric), ('ß', OtherAlphanumeric), ('漢', OtherAlphanumeric),
+        ('٣', OtherAlphanumeric), // Arabic-Indic three: numeric, but not in '0'..='9'
+        ('€', Other), ('🦀', Other), ('\0', Other), ('\u{7f}', Other),
+    ];
+    for (c, want) in cases {
+        assert_eq!(classify(c), want, "{c:?}");
+    }
+    println!("{:?}", "a1 !é".chars().map(classify).collect::<Vec<_>>());
+
+    // Ordering subtlety: a broad guard placed first silently shadows the ranges below it.
+    // The compiler can't tell (guards may fail), so there is no unreachable-pattern warning.
+    fn classify_guard_first(c: char) -> CharClass {
+        match c {
+            c if c.is_alphanumeric() => OtherAlphanumeric, // also true for '7' and 'a'!
+            d @ '0'..='9' => Digit(d as u32 - '0' as u32),
+            l @ ('a'..='z' | 'A'..='Z') => Letter { upper: l.is_ascii_uppercase() },
+            _ => Other,
+        }
+    }
+    assert_eq!(classify_guard_first('7'), OtherAlphanumeric); // the Digit arm never runs
+    assert_eq!(classify_guard_first('a'), OtherAlphanumeric);
+    assert_eq!(classify_guard_first('é'), classify('é'));      // agrees only off the ASCII ranges
+
+    // The reverse order is harmless for ranges: `'0'..='9'` is narrower than
+    // `is_numeric()`, so '٣' still falls through to the guard in `classify`.
+    assert!('٣'.is_numeric() && !'٣'.is_ascii_digit());
+}
+
 /*
 Docs-style notes:
 
@@ -202,19 +449,26 @@ Common pattern tools:
 - Guards       : `pat if condition`
 - `@` binding  : bind matched value while testing its shape (e.g., `n @ 0..=9`)
 - `&` / `&mut` : reference patterns peel ref layers (e.g., `&x`, `&mut y`)
-- `box`        : box pattern to move out of `Box<T>` (ownership transferred)
+- `box`        : box pattern to move out of `Box<T>` (nightly `box_patterns` only;
+                 on stable match `*b` / `b.as_ref()` / `opt.as_deref()` instead)
 
 Exhaustiveness:
 - `match` must be exhaustive. Add `_ => ...` or cover all variants.
 - Arm order matters; the first matching arm runs.
+- `#[non_exhaustive]` enums/structs force a `_` arm (or `..`) in *other* crates,
+  so the author can add variants/fields without a breaking change.
 
 Matching ergonomics:
 - Matching on references often auto-derefs; use `&pat` to bind by value of a reference.
 - Use `ref`/`ref mut` in older code; modern Rust prefers `&` / `&mut` patterns.
+- Ergonomics only kicks in when the scrutinee is a reference (`&T` / `&mut T`).
+  Matching a place (`*v`, an owned local) by value still needs `ref`/`ref mut`
+  to avoid moving out of it.
 
 Option/Result sugar:
 - `if let Some(x) = opt { ... }` for single-interest cases.
 - `while let Some(x) = iter.next() { ... }` to consume iterators.
+- `while let Some(x) = stack.pop() { ... }` drains a Vec LIFO and lets the body push more.
 
 Slices:
 - Array/slice patterns support `[a, b]`, `[head, ..]`, `[.., tail]`, `[h, mid @ .., t]`.
@@ -230,5 +484,7 @@ Bindings:
 Performance:
 - Patterns are zero-cost; the compiler generates optimal tests/binds.
 - Guards run only after the structural pattern matches.
+- A guard arm never makes later arms "unreachable" to the compiler: put narrow
+  ranges (`'0'..='9'`) before broad guards (`c if c.is_alphanumeric()`).
 
 */
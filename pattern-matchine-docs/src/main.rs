@@ -6,10 +6,14 @@ use pattern_matchine_docs::{
     ex_slice_patterns,
     ex_references_boxes,
     ex_while_let,
+    ex_while_let_drain,
     ex_matches_macro,
     ex_ignore_parts,
     ex_shadowing_and_order,
     ex_function_param_patterns,
+    ex_ref_bindings,
+    ex_non_exhaustive,
+    ex_char_classification,
 };
 
 fn main() {
@@ -20,8 +24,12 @@ fn main() {
     ex_slice_patterns();
     ex_references_boxes();
     ex_while_let();
+    ex_while_let_drain();
     ex_matches_macro();
     ex_ignore_parts();
     ex_shadowing_and_order();
     ex_function_param_patterns();
+    ex_ref_bindings();
+    ex_non_exhaustive();
+    ex_char_classification();
 }
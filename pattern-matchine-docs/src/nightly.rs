@@ -0,0 +1,10 @@
+//! Examples that need unstable syntax. Lives in its own file because a `#[cfg]`'d block
+//! is still *parsed* on stable (and warns about the unstable syntax); an out-of-line
+//! module behind `#[cfg(feature = "nightly")]` is never even read.
+
+/// The `box` pattern destructures through the Box in one step.
+pub(crate) fn ex_box_pattern() {
+    match Box::new(String::from("hello")) {
+        box s => println!("box pattern moved out: {s}"),
+    }
+}
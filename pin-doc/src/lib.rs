@@ -11,9 +11,15 @@
 //!  1) Unpin basics and "pin as a no-op" when T: Unpin
 //!  2) Pinning on the heap with `Box::pin` and address stability
 //!  3) A `!Unpin` type via `PhantomPinned`: what you *can* and *cannot* do
+//!     (and that pinned values are still dropped normally, in place)
 //!  4) Safe & unsafe APIs on `Pin`: `get_ref`, `get_mut` (needs `Unpin`), `as_mut`, `map_unchecked_mut`
+//!     (with an Unpin / !Unpin accessor matrix checked by `compile_fail` doctests)
 //!  5) Field projection basics (why it’s tricky) and a minimal, careful example
-//!  6) Notes on async/futures and pinning
+//!     (plus `PinnedBuffer`, a `!Unpin` buffer handing out borrowed windows,
+//!     and `Pinned<T>`, which keeps the projection `unsafe` in one audited place;
+//!     `OneShotSlot<T>` moves a non-pinned field out while the slot stays put)
+//!  6) Notes on async/futures and pinning (plus a self-borrowing async block driven by hand,
+//!     and `RangeGen`, a generator resumed through `Pin<&mut Self>`)
 //!
 //! Run with: `cargo run`
 
@@ -114,6 +120,64 @@ pub fn ex_non_unpin_type() {
     // let inner = Pin::into_inner(s); // ❌ requires T: Unpin; SelfRef is !Unpin
 }
 
+/* ───────────── 3b) Pinned values are still dropped normally ─────────────
+Pinning restricts *moves*, not *drops*. When a `Pin<Box<T>>` goes out of scope, `T::drop`
+runs exactly once, in place — at the address it was pinned at (the "drop guarantee": a
+pinned value's memory isn't reused or freed before its destructor runs). `Drop::drop`
+receives `&mut self`, but for a `!Unpin` type it must act as if it had `Pin<&mut Self>`:
+don't move out of `self` (no `mem::swap`/`take` on the whole value).
+*/
+struct DropProbe {
+    name: &'static str,
+    log: std::rc::Rc<std::cell::RefCell<Vec<(&'static str, usize)>>>,
+    _pin: PhantomPinned, // `!Unpin`
+}
+
+impl Drop for DropProbe {
+    fn drop(&mut self) {
+        // Only reads through `&mut self`; nothing is moved out, so the pin promise holds.
+        let addr = addr_of(self);
+        self.log.borrow_mut().push((self.name, addr));
+    }
+}
+
+pub fn ex_pinned_drop() {
+    println!("\n== 3b) Dropping pinned !Unpin values ==");
+    let log = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+    let probe = |name| DropProbe { name, log: log.clone(), _pin: PhantomPinned };
+
+    let pinned_at;
+    {
+        let p: Pin<Box<DropProbe>> = Box::pin(probe("boxed"));
+        pinned_at = addr_of(&*p);
+        let moved = vec![p]; // moving the *Box* around is fine; the value stays put
+        assert!(log.borrow().is_empty());
+        drop(moved);
+    }
+    let (name, dropped_at) = log.borrow()[0];
+    println!("dropped {name:?} at 0x{dropped_at:x}, pinned at 0x{pinned_at:x}");
+    assert_eq!(*log.borrow(), [("boxed", pinned_at)]); // exactly once, at the pinned address
+
+    // Several pins in one scope: reverse declaration order, as for any locals.
+    log.borrow_mut().clear();
+    {
+        let _a = Box::pin(probe("a"));
+        let _b = Box::pin(probe("b"));
+        let _c: Pin<&mut DropProbe> = std::pin::pin!(probe("c")); // stack pin: same rules
+    }
+    let names: Vec<_> = log.borrow().iter().map(|(n, _)| *n).collect();
+    assert_eq!(names, ["c", "b", "a"]);
+
+    // `Pin::set` replaces the value *in place*: the old one is dropped first, same address.
+    log.borrow_mut().clear();
+    let mut slot = Box::pin(probe("old"));
+    let at = addr_of(&*slot);
+    slot.set(probe("new"));
+    assert_eq!(*log.borrow(), [("old", at)]);
+    drop(slot);
+    assert_eq!(*log.borrow(), [("old", at), ("new", at)]);
+}
+
 /* ───────────── 4) Pin API: safe vs unsafe (and why) ─────────────
 Key methods (selected):
 - Pin::new(&mut T)            -> Pin<&mut T>              (safe)    // create pinned ref from &mut
@@ -154,6 +218,167 @@ pub fn ex_pin_api_and_projection() {
     // We won't do that here to keep things simple & safe.
 }
 
+/* ───────────── 4a) Accessor matrix: what compiles for Unpin vs !Unpin ─────────────
+| accessor                          | T: Unpin | T: !Unpin                             |
+|-----------------------------------|----------|---------------------------------------|
+| `Pin::get_ref(Pin<&T>) -> &T`     | ✅       | ✅                                    |
+| `Pin::as_mut(&mut Pin<P>)`        | ✅       | ✅ (stays pinned)                     |
+| `Pin::get_mut(Pin<&mut T>)`       | ✅       | ❌ (`get_unchecked_mut`, unsafe)      |
+| `Pin::into_inner(Pin<P>) -> P`    | ✅       | ❌ (`into_inner_unchecked`, unsafe)   |
+*/
+
+/// Exercises every accessor on an `Unpin` type. The `!Unpin` rows of the matrix
+/// are checked by the compiler — both of these are rejected:
+///
+/// ```compile_fail,E0277
+/// use std::{marker::PhantomPinned, pin::Pin};
+/// let mut p = Box::pin(PhantomPinned);
+/// let _m: &mut PhantomPinned = Pin::get_mut(p.as_mut()); // PhantomPinned: !Unpin
+/// ```
+///
+/// ```compile_fail,E0277
+/// use std::{marker::PhantomPinned, pin::Pin};
+/// let p = Box::pin(PhantomPinned);
+/// let _b: Box<PhantomPinned> = Pin::into_inner(p); // can't unpin a !Unpin value
+/// ```
+///
+/// while the always-allowed accessors still work on it:
+///
+/// ```
+/// use std::{marker::PhantomPinned, pin::Pin};
+/// let mut p = Box::pin(PhantomPinned);
+/// let _r: &PhantomPinned = Pin::get_ref(p.as_ref());
+/// let _m: Pin<&mut PhantomPinned> = p.as_mut();
+/// ```
+pub fn ex_pin_api_matrix() {
+    println!("\n== 4a) Pin accessor matrix on an Unpin type ==");
+    let mut p: Pin<Box<u32>> = Box::pin(10); // u32: Unpin, so the pin is only a formality
+
+    let r: &u32 = Pin::get_ref(p.as_ref());
+    assert_eq!(*r, 10);
+
+    let mut m: Pin<&mut u32> = p.as_mut();
+    m.set(11); // `set` works for any T (drops the old value in place)
+    assert_eq!(*m, 11);
+
+    let plain: &mut u32 = Pin::get_mut(p.as_mut()); // ok: u32: Unpin
+    *plain += 1;
+    assert_eq!(*p, 12);
+
+    let boxed: Box<u32> = Pin::into_inner(p); // ok: un-pinning an Unpin value is harmless
+    assert_eq!(*boxed, 12);
+
+    let moved = *boxed; // and it may now move freely
+    println!("get_ref → 10, as_mut.set → 11, get_mut += 1 → 12, into_inner → {moved}");
+    assert_eq!(moved, 12);
+
+    // The same on the stack: Pin::new only exists for Unpin targets.
+    let mut x = String::from("a");
+    let mut px = Pin::new(&mut x);
+    Pin::get_mut(px.as_mut()).push('b');
+    assert_eq!(Pin::into_inner(px), "ab");
+}
+
+/* ───────────── 4b) A pinned buffer handing out borrowed windows ─────────────
+`PinnedBuffer` is `!Unpin` and only ever constructed behind `Pin<Box<_>>`, so the struct
+(and the `Vec` header inside it) never relocates. The windows themselves owe nothing to the
+pin: `iter_windows` takes plain `&self`, so they are ordinary shared borrows and the borrow
+checker alone keeps them valid (it would for an unpinned `Vec` just the same). What the pin
+adds is for *raw* addresses kept without a borrow — e.g. a pointer to the struct handed to
+C, or a cursor a self-referential type would store — which stay correct across moves of
+the `Pin<Box<_>>` handle.
+*/
+pub struct PinnedBuffer {
+    data: Vec<u8>,
+    window: usize,
+    _pin: PhantomPinned, // makes the type `!Unpin`
+}
+
+impl PinnedBuffer {
+    /// The only constructor: the buffer is born pinned on the heap.
+    pub fn new(data: Vec<u8>, window: usize) -> Pin<Box<Self>> {
+        assert!(window > 0, "window size must be non-zero");
+        Box::pin(PinnedBuffer { data, window, _pin: PhantomPinned })
+    }
+
+    /// Overlapping windows of `window` bytes, each a normal `&self` borrow of the buffer.
+    pub fn iter_windows(&self) -> impl Iterator<Item = &[u8]> {
+        self.data.windows(self.window)
+    }
+}
+
+pub fn ex_pinned_buffer_windows() {
+    println!("\n== 4b) PinnedBuffer: windows borrowing a pinned buffer ==");
+    let buf = PinnedBuffer::new(b"pinned".to_vec(), 3);
+
+    let first_ptr = buf.iter_windows().next().unwrap().as_ptr();
+    let struct_addr = addr_of(&*buf);
+
+    // Moving the Pin<Box<_>> moves only the pointer, never the buffer.
+    let moved = buf;
+    let windows: Vec<&[u8]> = moved.iter_windows().collect();
+    for w in &windows {
+        println!("window = {:?}", std::str::from_utf8(w).unwrap());
+    }
+
+    assert_eq!(windows, [&b"pin"[..], b"inn", b"nne", b"ned"]);
+    assert_eq!(addr_of(&*moved), struct_addr); // the pin's guarantee: the struct stayed put
+    assert_eq!(windows[0].as_ptr(), first_ptr); // the bytes live in the Vec's heap block anyway
+
+    // let inner = Pin::into_inner(moved); // ❌ PinnedBuffer: !Unpin
+}
+
+/* ───────────── 4c) Shared pins: Pin<Arc<T>> and Pin<Rc<T>> ─────────────
+`Arc::pin(v)` / `Rc::pin(v)` allocate once and pin the value inside the shared allocation.
+Cloning the `Pin<Arc<T>>` clones the *pointer* (refcount + 1); every clone points at the
+same, never-moving `T`. What you lose is `&mut`: `Pin::as_mut` needs `DerefMut`, which
+`Arc`/`Rc` don't offer (other owners may be reading). Mutate through interior mutability
+(atomics, `Mutex`, `Cell`) reached via `&T` instead.
+*/
+#[derive(Debug)]
+struct SharedCounter {
+    hits: std::sync::atomic::AtomicUsize, // interior mutability: works through &T
+    _pin: PhantomPinned,                 // `!Unpin`, so the pin really matters
+}
+
+pub fn ex_pin_shared() {
+    use std::rc::Rc;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    println!("\n== 4c) Pin<Arc<T>> / Pin<Rc<T>> ==");
+
+    let a: Pin<Arc<SharedCounter>> =
+        Arc::pin(SharedCounter { hits: AtomicUsize::new(0), _pin: PhantomPinned });
+    let start = addr_of(&*a);
+
+    let clones: Vec<Pin<Arc<SharedCounter>>> = (0..3).map(|_| a.clone()).collect();
+    for c in &clones {
+        assert_eq!(addr_of(&**c), start); // same pinned value behind every clone
+        c.hits.fetch_add(1, Ordering::Relaxed); // mutation via &T, not &mut T
+    }
+    let moved = std::thread::spawn(move || {
+        let c = &clones[0];
+        c.hits.fetch_add(1, Ordering::Relaxed);
+        addr_of(&**c) // Pin<Arc<T>> is Send when T: Send + Sync
+    })
+    .join()
+    .unwrap();
+    assert_eq!(moved, start);
+    println!("Pin<Arc> inner addr = 0x{start:x}, hits = {:?}", a.hits);
+    assert_eq!(a.hits.load(Ordering::Relaxed), 4);
+
+    // let m: Pin<&mut SharedCounter> = a.as_mut(); // ❌ Arc is not DerefMut
+    // let inner = Pin::into_inner(a);              // ❌ SharedCounter: !Unpin
+
+    // Single-threaded twin: Pin<Rc<T>> with a Cell for interior mutation.
+    let r: Pin<Rc<std::cell::Cell<u32>>> = Rc::pin(std::cell::Cell::new(1));
+    let r2 = r.clone();
+    r2.set(r2.get() + 1);
+    assert_eq!(addr_of(&*r), addr_of(&*r2));
+    println!("Pin<Rc> value via either clone = {}", r.get());
+    assert_eq!(r.get(), 2);
+}
+
 /* ───────────── 5) Why field projection is hard (the short version) ─────────────
 If `T: !Unpin`, pinning `Pin<&mut T>` promises the *whole T* will not move.
 Projecting to a field and treating it as independently pinned requires proving that moving the
@@ -161,6 +386,134 @@ outer T cannot occur without also moving the field — which is why safe project
 Crates like `pin-project` generate correct projections for you. Here we just explain the idea.
 */
 
+/* ───────────── 5b) Pinned<T>: keeping the projection `unsafe` in one place ─────────────
+Instead of sprinkling `get_unchecked_mut` over call sites, wrap the value once and expose
+closures that receive plain references. `map_ref` is safe outright (`&T` can't move
+anything). `with_mut` hands out `&mut T`, which *could* move the field (`mem::swap`), so it
+requires `T: Unpin`: the field is not structurally pinned, only the wrapper's address is.
+That `Unpin` bound is the whole safety argument, written down exactly once.
+*/
+pub struct Pinned<T> {
+    inner: T,
+    _pin: PhantomPinned, // wrapper is `!Unpin` even when T is
+}
+
+impl<T> Pinned<T> {
+    pub fn new(t: T) -> Pin<Box<Self>> {
+        Box::pin(Pinned { inner: t, _pin: PhantomPinned })
+    }
+
+    /// Read the inner value; the reference can't escape the closure.
+    pub fn map_ref<R>(self: Pin<&Self>, f: impl FnOnce(&T) -> R) -> R {
+        f(&self.get_ref().inner)
+    }
+
+    /// Mutate the inner value in place.
+    pub fn with_mut<R>(self: Pin<&mut Self>, f: impl FnOnce(&mut T) -> R) -> R
+    where
+        T: Unpin,
+    {
+        // SAFETY: we never move `*self`; only `&mut inner` leaves this function, and
+        // `inner` is not treated as pinned (T: Unpin), so moving *it* breaks no promise.
+        let this = unsafe { self.get_unchecked_mut() };
+        f(&mut this.inner)
+    }
+}
+
+pub fn ex_pinned_wrapper() {
+    println!("\n== 5b) Pinned<T> wrapper: map_ref / with_mut ==");
+    let mut p: Pin<Box<Pinned<Vec<u32>>>> = Pinned::new(vec![1, 2, 3]);
+
+    let sum = p.as_ref().map_ref(|v| v.iter().sum::<u32>());
+    assert_eq!(sum, 6);
+
+    let inner_addr = p.as_ref().map_ref(addr_of);
+    let new_len = p.as_mut().with_mut(|v| {
+        v.push(4);
+        v.len()
+    });
+    assert_eq!(new_len, 4);
+
+    // Move the owning pointer around: into a Vec, then back out.
+    let mut holder = vec![p];
+    let p = holder.pop().unwrap();
+    let (addr_after, contents) = p.as_ref().map_ref(|v| (addr_of(v), v.clone()));
+    println!("inner at 0x{inner_addr:x} before move, 0x{addr_after:x} after; contents = {contents:?}");
+    assert_eq!(addr_after, inner_addr); // the wrapper (and its field) never relocated
+    assert_eq!(contents, [1, 2, 3, 4]);
+
+    // Pinned::new(PhantomPinned).as_mut().with_mut(|_| ()); // ❌ PhantomPinned: !Unpin
+    // let _ = Pin::into_inner(p);                          // ❌ Pinned<_>: !Unpin
+}
+
+/* ───────────── 5c) OneShotSlot<T>: moving a value *out of* a pinned struct ─────────────
+The slot itself is `!Unpin` and lives in a `Pin<Box<_>>`; its address never changes
+(think: a waker registration or an intrusive list node pointing at it). The `value` field,
+however, is *not* structurally pinned: no API ever hands out `Pin<&mut T>` to it. So `take`
+may `Option::take` the value out while the slot stays put. No `T: Unpin` bound is needed;
+the only promise being kept is about the slot's address, not the payload's.
+*/
+pub struct OneShotSlot<T> {
+    value: Option<T>,
+    _pin: PhantomPinned,
+}
+
+impl<T> OneShotSlot<T> {
+    pub fn new() -> Pin<Box<Self>> {
+        Box::pin(OneShotSlot { value: None, _pin: PhantomPinned })
+    }
+
+    /// Fill the slot once; a second `set` before `take` hands the value back.
+    pub fn set(self: Pin<&mut Self>, value: T) -> Result<(), T> {
+        // SAFETY: `*self` is not moved; `value` is a plain (non-pinned) field.
+        let this = unsafe { self.get_unchecked_mut() };
+        match this.value {
+            Some(_) => Err(value),
+            None => {
+                this.value = Some(value);
+                Ok(())
+            }
+        }
+    }
+
+    /// Move the value out, leaving the (still pinned) slot empty.
+    pub fn take(self: Pin<&mut Self>) -> Option<T> {
+        // SAFETY: only the unpinned `value` field moves; the slot stays in place.
+        unsafe { self.get_unchecked_mut() }.value.take()
+    }
+
+    pub fn is_set(&self) -> bool {
+        self.value.is_some()
+    }
+}
+
+pub fn ex_one_shot_slot() {
+    println!("\n== 5c) OneShotSlot<T>: take() out of a pinned !Unpin struct ==");
+    let mut slot: Pin<Box<OneShotSlot<String>>> = OneShotSlot::new();
+    let addr = addr_of(&*slot);
+
+    assert!(slot.as_mut().set("hello".to_string()).is_ok());
+    assert_eq!(slot.as_mut().set("again".to_string()), Err("again".to_string()));
+    assert!(slot.is_set());
+
+    // Move the Pin<Box<_>> (into another binding, through a function): the slot stays put.
+    let moved = slot;
+    let mut slot = std::convert::identity(moved);
+    assert_eq!(addr_of(&*slot), addr);
+
+    let got = slot.as_mut().take();
+    println!("took {got:?}; slot still at 0x{addr:x}, set = {}", slot.is_set());
+    assert_eq!(got.as_deref(), Some("hello"));
+    assert_eq!(slot.as_mut().take(), None); // one-shot: empty now
+    assert_eq!(addr_of(&*slot), addr);
+
+    // Reusable after draining, still at the same address.
+    slot.as_mut().set("second".into()).unwrap();
+    assert_eq!(slot.as_mut().take().as_deref(), Some("second"));
+
+    // let inner = Pin::into_inner(slot); // ❌ OneShotSlot<_>: !Unpin
+}
+
 /* ───────────── 6) Async & pinning (conceptual) ─────────────
 - `async fn` returns an *anonymous* `impl Future<Output = T>` that is **usually `!Unpin`**.
 - Executors (Tokio/etc.) **pin** futures before polling them: the state machine inside stores
@@ -175,6 +528,113 @@ Crates like `pin-project` generate correct projections for you. Here we just exp
 */
 
 
+/* ───────────── 6b) An async block that borrows itself across `.await` ─────────────
+The compiler turns an `async` block into an enum-like state machine. Locals that live
+across an `.await` become *fields* of that state; a reference to such a local becomes a
+pointer from one field to another → a self-referential struct. Moving it after the first
+poll would leave that pointer dangling, so the generated future is `!Unpin` and must be
+pinned (`Box::pin` here) before `poll`. Roughly:
+    enum State { Start, Suspended { data: [i32; 3], first: *const i32 /* → data */, .. }, Done }
+*/
+
+/// Returns `Pending` once, then `Ready` — forces a real suspension point.
+struct YieldOnce(bool);
+
+impl std::future::Future for YieldOnce {
+    type Output = ();
+    fn poll(mut self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> std::task::Poll<()> {
+        if self.0 {
+            std::task::Poll::Ready(())
+        } else {
+            self.0 = true; // YieldOnce is Unpin, so Pin<&mut Self> derefs mutably
+            cx.waker().wake_by_ref();
+            std::task::Poll::Pending
+        }
+    }
+}
+
+pub fn ex_async_self_ref() {
+    use std::future::Future;
+    use std::task::{Context, Poll, Waker};
+    println!("\n== 6b) async block holding a borrow across .await ==");
+
+    let fut = async {
+        let data = [10, 20, 30];       // stored inside the future's state
+        let first: &i32 = &data[0];    // points into that same state
+        YieldOnce(false).await;        // suspend: both `data` and `first` are kept
+        *first + data.len() as i32     // borrow still valid after resuming
+    };
+    // fn require_unpin<F: Unpin>(_: &F) {}
+    // require_unpin(&fut); // ❌ the async block is !Unpin
+
+    let mut fut = Box::pin(fut); // pin first: address fixed before the first poll
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut polls = 0;
+    let out = loop {
+        polls += 1;
+        if let Poll::Ready(v) = fut.as_mut().poll(&mut cx) {
+            break v;
+        }
+    };
+    println!("async block finished after {polls} polls with {out}");
+    assert_eq!((out, polls), (13, 2));
+}
+
+/* ───────────── 6c) A hand-written generator: `resume(self: Pin<&mut Self>)` ─────────────
+A generator/coroutine is a state machine that runs until it yields, then continues from
+where it stopped on the next `resume`. Compiler-generated ones (`async` blocks, nightly
+`Coroutine`) keep locals that live across a yield *inside* the state, possibly borrowing each
+other (6b) — so `resume` takes `Pin<&mut Self>`, exactly like `Future::poll`, promising the
+state never moves between resumptions. `RangeGen` has no self-borrows of its own; it just
+follows that contract (`PhantomPinned` makes it `!Unpin`), so a richer state could add them.
+*/
+pub struct RangeGen {
+    current: u32,
+    end: u32,
+    _pin: PhantomPinned,
+}
+
+impl RangeGen {
+    pub fn new(start: u32, end: u32) -> Pin<Box<Self>> {
+        Box::pin(RangeGen { current: start, end, _pin: PhantomPinned })
+    }
+
+    /// Yields `start..end` one value per call, then `None` forever.
+    pub fn resume(self: Pin<&mut Self>) -> Option<u32> {
+        // SAFETY: we only update integer fields in place; nothing is moved out of `*self`.
+        let this = unsafe { self.get_unchecked_mut() };
+        if this.current >= this.end {
+            return None;
+        }
+        this.current += 1;
+        Some(this.current - 1)
+    }
+}
+
+pub fn ex_range_generator() {
+    println!("\n== 6c) RangeGen: a pinned, resumable state machine ==");
+    let mut g = RangeGen::new(3, 8);
+    let addr = addr_of(&*g);
+    let mut out = vec![g.as_mut().resume().unwrap(), g.as_mut().resume().unwrap()];
+
+    // Hand the generator off mid-run (a move of the Pin<Box<_>>, like a task being moved
+    // between executor queues); the state itself stays where it was and resumes from there.
+    let mut queue = std::collections::VecDeque::from([g]);
+    let mut g = queue.pop_front().unwrap();
+    assert_eq!(addr_of(&*g), addr);
+    out.extend(std::iter::from_fn(|| g.as_mut().resume()));
+    println!("yielded {out:?}");
+    assert_eq!(out, [3, 4, 5, 6, 7]);
+
+    // Exhausted stays exhausted; an empty range yields nothing.
+    assert_eq!(g.as_mut().resume(), None);
+    assert_eq!(g.as_mut().resume(), None);
+    assert_eq!(RangeGen::new(5, 5).as_mut().resume(), None);
+
+    // let r = *g;                   // ❌ cannot move out of a Pin<Box<_>>
+    // let _ = Pin::into_inner(g);   // ❌ RangeGen: !Unpin
+}
+
 /*
 Docs-style notes:
 
@@ -191,7 +651,8 @@ WHAT PINNING GUARANTEES
 HOW TO CREATE PINS
 - Stack reference: `Pin::new(&mut t)` → `Pin<&mut T>` (valid for the borrow's lifetime).
 - Heap allocation: `Box::pin(t)` → `Pin<Box<T>>` (common for long-lived / async cases).
-- There are also APIs to pin in `Rc`/`Arc` on newer Rust versions; in practice, `Box::pin` is most common.
+- Shared heap pin: `Arc::pin(t)` / `Rc::pin(t)` → `Pin<Arc<T>>` / `Pin<Rc<T>>`; clones share one stable
+  address, but there is no `Pin<&mut T>` — mutate via interior mutability. `Box::pin` is most common.
 
 SAFE ACCESSORS
 - `Pin::get_ref(&Pin<&T>) -> &T`                 // shared access
@@ -215,6 +676,8 @@ ASYNC CONNECTION
 
 COMMON PITFALLS
 - Thinking pinning prevents mutation—no, it prevents *relocation*. You can still mutate content.
+- Thinking pinning prevents dropping—no, `Drop` runs as usual, in place. In `drop(&mut self)` of a
+  `!Unpin` type, treat `self` as pinned: don't move out of it.
 - Using `get_mut`/`into_inner` on `!Unpin` types—won’t compile (that’s the point).
 - Hand-rolling unsafe projection when you could use `pin-project(-lite)`.
 
@@ -2,14 +2,30 @@ use pin_doc::{
     ex_unpin_basics,
     ex_box_pin_address_stability,
     ex_non_unpin_type,
+    ex_pinned_drop,
     ex_pin_api_and_projection,
+    ex_pin_api_matrix,
+    ex_pinned_buffer_windows,
+    ex_pin_shared,
+    ex_pinned_wrapper,
+    ex_one_shot_slot,
+    ex_async_self_ref,
+    ex_range_generator,
 };
 
 fn main() {
     ex_unpin_basics();
     ex_box_pin_address_stability();
     ex_non_unpin_type();
+    ex_pinned_drop();
     ex_pin_api_and_projection();
+    ex_pin_api_matrix();
+    ex_pinned_buffer_windows();
+    ex_pin_shared();
+    ex_pinned_wrapper();
+    ex_one_shot_slot();
+    ex_async_self_ref();
+    ex_range_generator();
 
     println!("\n== Extra notes ==");
     println!("Most types are Unpin; pinning primarily matters for `!Unpin` (self-referential, async state).");
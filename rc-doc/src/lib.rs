@@ -12,6 +12,7 @@
 //! - Rc<Something> + Weak<Something> -> shared graphs without cycles
 
 use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use std::rc::{Rc, Weak};
 
 pub fn example_basic() {
@@ -108,6 +109,311 @@ pub fn example_weak_to_avoid_cycles() {
 }
 
 
+#[derive(Debug)]
+struct DagNode {
+    name: String,
+    children: RefCell<Vec<Rc<DagNode>>>, // RefCell lets us add edges after creation
+}
+
+fn dag_node(name: &str) -> Rc<DagNode> {
+    Rc::new(DagNode { name: name.into(), children: RefCell::new(Vec::new()) })
+}
+
+// Depth-first walk that visits each *node* once, even if it is reachable along
+// several paths (identity = the Rc's heap address, not the name).
+fn visit_once(root: &Rc<DagNode>) -> Vec<String> {
+    let mut seen: HashSet<*const DagNode> = HashSet::new();
+    let mut order = Vec::new();
+    let mut stack = vec![Rc::clone(root)];
+    while let Some(node) = stack.pop() {
+        if !seen.insert(Rc::as_ptr(&node)) {
+            continue; // shared subtree already visited
+        }
+        order.push(node.name.clone());
+        for child in node.children.borrow().iter().rev() {
+            stack.push(Rc::clone(child));
+        }
+    }
+    order
+}
+
+// A plain "visited" set can't tell sharing from cycles (both revisit a node).
+// Track the nodes on the current DFS path: meeting one of those again is a cycle.
+fn has_cycle(root: &Rc<DagNode>) -> bool {
+    fn dfs(node: &Rc<DagNode>, on_path: &mut HashSet<*const DagNode>, done: &mut HashSet<*const DagNode>) -> bool {
+        let id = Rc::as_ptr(node);
+        if on_path.contains(&id) {
+            return true; // back-edge
+        }
+        if !done.insert(id) {
+            return false; // shared node, already fully explored
+        }
+        on_path.insert(id);
+        let found = node.children.borrow().iter().any(|c| dfs(c, on_path, done));
+        on_path.remove(&id);
+        found
+    }
+    dfs(root, &mut HashSet::new(), &mut HashSet::new())
+}
+
+pub fn example_shared_dag() {
+    println!("\n== Example 5: Shared subtrees (DAG) and cycle detection ==");
+    //        top
+    //       /   \
+    //    left   right
+    //       \   /
+    //       bottom      <- one node, two parents
+    let (top, left, right, bottom) = (dag_node("top"), dag_node("left"), dag_node("right"), dag_node("bottom"));
+    top.children.borrow_mut().extend([left.clone(), right.clone()]);
+    left.children.borrow_mut().push(bottom.clone());
+    right.children.borrow_mut().push(bottom.clone());
+    drop(bottom); // now only the two parents own it
+
+    let shared = Rc::clone(&left.children.borrow()[0]);
+    println!("bottom strong_count (2 parents + this handle) = {}", Rc::strong_count(&shared));
+    assert_eq!(Rc::strong_count(&shared), 3);
+    drop(shared);
+    assert_eq!(Rc::strong_count(&left.children.borrow()[0]), 2);
+
+    let order = visit_once(&top);
+    println!("visit order = {:?}", order);
+    assert_eq!(order, ["top", "left", "bottom", "right"]); // bottom visited once
+    assert!(!has_cycle(&top)); // sharing is not a cycle
+
+    // Accidental cycle through RefCell: bottom -> top. This would leak all four nodes.
+    let bottom = Rc::clone(&left.children.borrow()[0]);
+    bottom.children.borrow_mut().push(top.clone());
+    assert!(has_cycle(&top));
+    println!("cycle detected after adding bottom -> top");
+
+    // Break it again so everything is freed (or use Weak for such back-edges).
+    bottom.children.borrow_mut().clear();
+    assert!(!has_cycle(&top));
+    assert_eq!(Rc::strong_count(&top), 1);
+}
+
+// Doubly-linked list: `next` links own the following node (strong), `prev` links
+// only observe the previous one (Weak), so there is no a <-> b strong cycle.
+// Each node is `Rc<RefCell<..>>`: shared by its predecessor (and `tail`), and
+// mutable in place when a neighbour is inserted.
+type DLink<T> = Option<Rc<RefCell<DNode<T>>>>;
+
+struct DNode<T> {
+    value: T,
+    next: DLink<T>,
+    prev: Weak<RefCell<DNode<T>>>,
+}
+
+pub struct DList<T> {
+    head: DLink<T>,
+    tail: DLink<T>,
+    len: usize,
+}
+
+impl<T> DList<T> {
+    pub fn new() -> Self {
+        DList { head: None, tail: None, len: 0 }
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        let node = Rc::new(RefCell::new(DNode { value, next: self.head.take(), prev: Weak::new() }));
+        match &node.borrow().next {
+            Some(old_head) => old_head.borrow_mut().prev = Rc::downgrade(&node),
+            None => self.tail = Some(node.clone()), // list was empty
+        }
+        self.head = Some(node);
+        self.len += 1;
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        let prev = self.tail.as_ref().map(Rc::downgrade).unwrap_or_default();
+        let node = Rc::new(RefCell::new(DNode { value, next: None, prev }));
+        match self.tail.take() {
+            Some(old_tail) => old_tail.borrow_mut().next = Some(node.clone()),
+            None => self.head = Some(node.clone()), // list was empty
+        }
+        self.tail = Some(node);
+        self.len += 1;
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Values are behind RefCell, so the iterators hand out clones rather than `&T`
+    // (a `Ref` guard can't outlive the step that produced it).
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_
+    where
+        T: Clone,
+    {
+        let mut cur = self.head.clone();
+        std::iter::from_fn(move || {
+            let node = cur.take()?;
+            let n = node.borrow();
+            cur = n.next.clone();
+            Some(n.value.clone())
+        })
+    }
+
+    pub fn iter_rev(&self) -> impl Iterator<Item = T> + '_
+    where
+        T: Clone,
+    {
+        let mut cur = self.tail.clone();
+        std::iter::from_fn(move || {
+            let node = cur.take()?;
+            let n = node.borrow();
+            cur = n.prev.upgrade(); // None at the head (its prev is an empty Weak)
+            Some(n.value.clone())
+        })
+    }
+
+    pub fn clear(&mut self) {
+        self.tail = None;
+        self.len = 0;
+        // Unlink one node at a time: the default drop would recurse once per node.
+        let mut cur = self.head.take();
+        while let Some(node) = cur {
+            cur = node.borrow_mut().next.take();
+        }
+    }
+}
+
+impl<T> Default for DList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for DList<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+pub fn example_doubly_linked_list() {
+    println!("\n== Example 6: Doubly-linked list with Weak back-links ==");
+    let mut list = DList::new();
+    list.push_back(2);
+    list.push_back(3);
+    list.push_front(1);
+    list.push_back(4);
+
+    let forward: Vec<i32> = list.iter().collect();
+    let mut backward: Vec<i32> = list.iter_rev().collect();
+    println!("forward = {:?}, backward = {:?}", forward, backward);
+    assert_eq!(forward, [1, 2, 3, 4]);
+    backward.reverse();
+    assert_eq!(backward, forward);
+    assert_eq!(list.len(), 4);
+
+    // Only `next` (and `tail`) are strong: interior nodes have exactly one owner.
+    let mut probes = Vec::new();
+    let mut cur = list.head.clone();
+    while let Some(node) = cur {
+        probes.push(Rc::downgrade(&node));
+        cur = node.borrow().next.clone();
+    }
+    let counts: Vec<usize> = probes.iter().map(Weak::strong_count).collect();
+    assert_eq!(counts, [1, 1, 1, 2]); // the last one is also held by `tail`
+
+    list.clear();
+    assert!(list.is_empty() && list.iter().next().is_none());
+    assert!(probes.iter().all(|w| w.strong_count() == 0)); // every node freed, no leak
+    println!("after clear: live nodes = {}", probes.iter().filter(|w| w.upgrade().is_some()).count());
+
+    // Edge cases: empty and single-node lists.
+    let empty: DList<i32> = DList::new();
+    assert_eq!((empty.iter().count(), empty.iter_rev().count()), (0, 0));
+    let mut one = DList::new();
+    one.push_front("solo");
+    assert_eq!(one.iter().collect::<Vec<_>>(), ["solo"]);
+    assert_eq!(one.iter_rev().collect::<Vec<_>>(), ["solo"]);
+    let solo = Rc::downgrade(one.head.as_ref().unwrap());
+    assert_eq!(solo.strong_count(), 2); // head and tail are the same node
+    drop(one);
+    assert_eq!(solo.strong_count(), 0);
+
+    // Long lists drop without recursion thanks to the iterative clear().
+    let mut long = DList::new();
+    for i in 0..100_000 {
+        long.push_back(i);
+    }
+    assert_eq!(long.iter_rev().next(), Some(99_999));
+}
+
+// Layered configuration (defaults < file < env < CLI, say). Each layer is an immutable
+// `Rc<HashMap>`: cloning the stack clones a Vec of pointers, so every copy shares the
+// same maps. A copy can then push/pop its own layers without touching the others.
+// Copy-on-write happens at layer granularity: nothing is ever mutated in place, a change
+// is just a new layer on top.
+#[derive(Clone, Default)]
+pub struct ConfigStack {
+    layers: Vec<Rc<HashMap<String, String>>>,
+}
+
+impl ConfigStack {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, layer: HashMap<String, String>) {
+        self.layers.push(Rc::new(layer));
+    }
+
+    pub fn pop(&mut self) -> Option<Rc<HashMap<String, String>>> {
+        self.layers.pop()
+    }
+
+    /// Top-down: the most recently pushed layer that has `key` wins.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.layers.iter().rev().find_map(|layer| layer.get(key)).map(String::as_str)
+    }
+
+    pub fn depth(&self) -> usize {
+        self.layers.len()
+    }
+}
+
+pub fn example_config_stack() {
+    println!("\n== Example 7: Layered config sharing Rc'd layers ==");
+    fn layer(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|&(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    let mut base = ConfigStack::new();
+    base.push(layer(&[("host", "localhost"), ("port", "8080"), ("log", "info")]));
+    base.push(layer(&[("port", "9000")]));
+    assert_eq!(base.get("port"), Some("9000")); // higher layer shadows
+    assert_eq!(base.get("host"), Some("localhost")); // falls through to the bottom
+    assert_eq!(base.get("missing"), None);
+
+    // Clone = new Vec of the same Rc pointers; no HashMap is copied.
+    let mut request = base.clone();
+    assert!(base.layers.iter().all(|l| Rc::strong_count(l) == 2));
+    assert!(Rc::ptr_eq(&base.layers[0], &request.layers[0]));
+    request.push(layer(&[("log", "debug")]));
+    println!("base log = {:?}, request log = {:?}", base.get("log"), request.get("log"));
+    assert_eq!((base.get("log"), request.get("log")), (Some("info"), Some("debug")));
+    assert_eq!((base.depth(), request.depth()), (2, 3));
+
+    // Popping from one copy leaves the others alone; the popped map survives as long as
+    // someone still holds it.
+    let popped = request.pop().unwrap();
+    assert_eq!(Rc::strong_count(&popped), 1); // nobody else had this layer
+    let port_layer = request.pop().unwrap();
+    assert_eq!(Rc::strong_count(&port_layer), 2); // still in `base`
+    assert_eq!(request.get("port"), Some("8080"));
+    assert_eq!(base.get("port"), Some("9000"));
+    drop(port_layer);
+    drop(request);
+    assert!(base.layers.iter().all(|l| Rc::strong_count(l) == 1));
+}
+
 /*
 Docs-style notes:
 
@@ -131,6 +437,10 @@ Mutation pattern:
 Avoiding cycles:
 - Graphs/trees with parent <-> child links can create Rc cycles -> memory leak
 - Use Weak<T> for back-edges (parents) to break cycles
+- Sharing a subtree (a DAG, one child with several Rc parents) is fine; only cycles leak.
+- Detect cycles with a DFS that tracks the *current path*, not just a visited set
+- Doubly-linked list: `next: Option<Rc<RefCell<Node>>>` (strong), `prev: Weak<..>`;
+  clear it iteratively, since dropping a long strong chain recurses per node
 
 Threading:
 - Rc<T> is !Send and !Sync (not thread-safe)
@@ -3,6 +3,9 @@ use rc::{
     example_tree_like_sharing,
     example_mutation_with_refcell,
     example_weak_to_avoid_cycles,
+    example_shared_dag,
+    example_doubly_linked_list,
+    example_config_stack,
 };
 
 fn main() {
@@ -10,4 +13,7 @@ fn main() {
     example_tree_like_sharing();
     example_mutation_with_refcell();
     example_weak_to_avoid_cycles();
+    example_shared_dag();
+    example_doubly_linked_list();
+    example_config_stack();
 }
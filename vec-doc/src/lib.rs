@@ -52,6 +52,8 @@
 //!   - Zero-sized types (ZSTs) like `()` have special handling (ptr may be dangling, len counts).
 //!   - `into_boxed_slice()` can trim spare capacity and store tightly (good for long-lived data).
 
+use std::collections::{HashSet, VecDeque};
+use std::hash::Hash;
 use std::mem::{size_of, size_of_val};
 
 pub fn example_vec_basics() {
@@ -314,6 +316,554 @@ pub fn example_safety_and_panic_free() {
 }
 
 
+// Run-length encoding: collapse adjacent equal elements into (value, count).
+fn rle_encode<T: PartialEq + Clone>(xs: &[T]) -> Vec<(T, usize)> {
+    let mut runs: Vec<(T, usize)> = Vec::new(); // unknown run count → let it grow
+    for x in xs {
+        match runs.last_mut() {
+            Some((last, n)) if last == x => *n += 1,
+            _ => runs.push((x.clone(), 1)),
+        }
+    }
+    runs
+}
+
+fn rle_decode<T: Clone>(runs: &[(T, usize)]) -> Vec<T> {
+    let total = runs.iter().map(|(_, n)| n).sum();
+    let mut out = Vec::with_capacity(total); // exact size known → one allocation
+    for (x, n) in runs {
+        out.extend(std::iter::repeat_n(x.clone(), *n));
+    }
+    out
+}
+
+pub fn example_run_length_encoding() {
+    println!("\n== Run-length encode/decode over slices ==");
+    let runs_heavy = [7, 7, 7, 1, 1, 9, 9, 9, 9, 7];
+    let encoded = rle_encode(&runs_heavy);
+    println!("encode({:?}) = {:?}", runs_heavy, encoded);
+    assert_eq!(encoded, [(7, 3), (1, 2), (9, 4), (7, 1)]);
+
+    let decoded = rle_decode(&encoded);
+    println!("decode -> {:?} (len={}, cap={})", decoded, decoded.len(), decoded.capacity());
+    assert_eq!(decoded, runs_heavy);
+    assert_eq!(decoded.capacity(), decoded.len()); // capacity hint was exact
+
+    // Round-trips over edge cases: single element, empty, no adjacent duplicates.
+    let text: Vec<char> = "aaabccdddd".chars().collect();
+    let cases: [&[char]; 4] = [&text, &['x'], &[], &['a', 'b', 'a', 'b']];
+    for xs in cases {
+        let enc = rle_encode(xs);
+        assert_eq!(rle_decode(&enc), xs);
+        println!("{:?} -> {} run(s)", xs.iter().collect::<String>(), enc.len());
+    }
+    assert!(rle_encode::<char>(&[]).is_empty());
+    assert_eq!(rle_encode(&['a', 'b', 'a', 'b']).len(), 4); // nothing to collapse
+}
+
+// Keep `v` sorted while inserting; returns the index the value landed at.
+// Duplicates go *after* existing equal elements (upper bound), so equal values keep
+// their insertion order — the same "stable" choice `sort` makes.
+// Plain `binary_search(&value)` returns *some* matching index when duplicates exist,
+// so we search with a comparator that never reports Equal: it always ends in Err(idx)
+// where idx is the first position holding a greater element.
+fn insert_sorted<T: Ord>(v: &mut Vec<T>, value: T) -> usize {
+    let idx = v
+        .binary_search_by(|probe| if *probe <= value { std::cmp::Ordering::Less } else { std::cmp::Ordering::Greater })
+        .unwrap_or_else(|i| i);
+    v.insert(idx, value); // O(log n) search + O(n) shift
+    idx
+}
+
+pub fn example_insert_sorted() {
+    println!("\n== Sorted insert via binary search ==");
+    let mut v: Vec<i32> = Vec::new();
+    assert_eq!(insert_sorted(&mut v, 50), 0); // empty
+    assert_eq!(insert_sorted(&mut v, 10), 0); // front
+    assert_eq!(insert_sorted(&mut v, 90), 2); // back
+    assert_eq!(insert_sorted(&mut v, 30), 1); // middle
+    println!("after inserts: {:?}", v);
+    assert_eq!(v, [10, 30, 50, 90]);
+
+    // Duplicate: lands after the existing 30.
+    assert_eq!(insert_sorted(&mut v, 30), 2);
+    assert_eq!(insert_sorted(&mut v, 30), 3);
+    println!("after duplicate 30s: {:?}", v);
+    assert_eq!(v, [10, 30, 30, 30, 50, 90]);
+    assert!(v.windows(2).all(|w| w[0] <= w[1]));
+
+    // "After equals" matters when equal keys carry different payloads:
+    // jobs ordered by priority only stay FIFO within the same priority.
+    #[derive(Debug)]
+    struct Job { prio: u8, name: &'static str }
+    impl PartialEq for Job { fn eq(&self, o: &Self) -> bool { self.prio == o.prio } }
+    impl Eq for Job {}
+    impl PartialOrd for Job { fn partial_cmp(&self, o: &Self) -> Option<std::cmp::Ordering> { Some(self.cmp(o)) } }
+    impl Ord for Job { fn cmp(&self, o: &Self) -> std::cmp::Ordering { self.prio.cmp(&o.prio) } }
+
+    let mut queue = Vec::new();
+    for (prio, name) in [(2, "b1"), (1, "a1"), (2, "b2"), (1, "a2")] {
+        insert_sorted(&mut queue, Job { prio, name });
+    }
+    let names: Vec<_> = queue.iter().map(|j| j.name).collect();
+    println!("priority queue (FIFO within prio): {:?}", names);
+    assert_eq!(names, ["a1", "a2", "b1", "b2"]);
+}
+
+// Stack and queue on top of std buffers.
+// - Stack (LIFO): push/pop at the *end* of a Vec → O(1) amortized. Growth doubles the
+//   capacity, so n pushes cost O(n) total copies plus only ~log2(n) reallocations.
+// - Queue (FIFO): popping the *front* of a Vec is `remove(0)`, which shifts the other
+//   n-1 elements left → O(n) per pop. `VecDeque` is a ring buffer: the head index just
+//   moves forward, so both ends are O(1) (amortized for push).
+// `capacity_hint` exposes the underlying allocation for sizing / `with_capacity` tuning.
+pub struct VecStack<T> {
+    items: Vec<T>,
+}
+
+impl<T> VecStack<T> {
+    pub fn new() -> Self { VecStack { items: Vec::new() } }
+    pub fn with_capacity(cap: usize) -> Self { VecStack { items: Vec::with_capacity(cap) } }
+    pub fn push(&mut self, value: T) { self.items.push(value); }
+    pub fn pop(&mut self) -> Option<T> { self.items.pop() }
+    pub fn peek(&self) -> Option<&T> { self.items.last() }
+    pub fn len(&self) -> usize { self.items.len() }
+    pub fn is_empty(&self) -> bool { self.items.is_empty() }
+    pub fn capacity_hint(&self) -> usize { self.items.capacity() }
+}
+
+impl<T> Default for VecStack<T> {
+    fn default() -> Self { Self::new() }
+}
+
+pub struct VecQueue<T> {
+    items: VecDeque<T>,
+}
+
+impl<T> VecQueue<T> {
+    pub fn new() -> Self { VecQueue { items: VecDeque::new() } }
+    pub fn with_capacity(cap: usize) -> Self { VecQueue { items: VecDeque::with_capacity(cap) } }
+    pub fn push(&mut self, value: T) { self.items.push_back(value); }
+    pub fn pop(&mut self) -> Option<T> { self.items.pop_front() }
+    pub fn peek(&self) -> Option<&T> { self.items.front() }
+    pub fn len(&self) -> usize { self.items.len() }
+    pub fn is_empty(&self) -> bool { self.items.is_empty() }
+    pub fn capacity_hint(&self) -> usize { self.items.capacity() }
+}
+
+impl<T> Default for VecQueue<T> {
+    fn default() -> Self { Self::new() }
+}
+
+pub fn example_stack_and_queue() {
+    println!("\n== Vec-backed stack vs VecDeque-backed queue ==");
+    let mut stack = VecStack::new();
+    let mut queue = VecQueue::new();
+    for x in 1..=3 {
+        stack.push(x);
+        queue.push(x);
+    }
+    assert_eq!((stack.peek(), queue.peek()), (Some(&3), Some(&1)));
+    let lifo: Vec<_> = std::iter::from_fn(|| stack.pop()).collect();
+    let fifo: Vec<_> = std::iter::from_fn(|| queue.pop()).collect();
+    println!("stack pops {:?}, queue pops {:?}", lifo, fifo);
+    assert_eq!(lifo, [3, 2, 1]);
+    assert_eq!(fifo, [1, 2, 3]);
+    assert!(stack.is_empty() && queue.is_empty() && stack.pop().is_none());
+
+    // Interleaved push/pop: the queue still hands items out in arrival order,
+    // even once the ring buffer's head has wrapped around.
+    let mut q = VecQueue::with_capacity(4);
+    let mut out = Vec::new();
+    for round in 0..5 {
+        q.push(round * 2);
+        q.push(round * 2 + 1);
+        out.extend(q.pop());
+    }
+    while let Some(x) = q.pop() { out.push(x); }
+    println!("interleaved queue output: {:?}", out);
+    assert_eq!(out, (0..10).collect::<Vec<_>>());
+
+    // Amortized growth: 1000 pushes, but only a handful of reallocations.
+    let mut s = VecStack::new();
+    let mut reallocs = 0;
+    for i in 0..1000 {
+        let before = s.capacity_hint();
+        s.push(i);
+        if s.capacity_hint() != before { reallocs += 1; }
+    }
+    println!("1000 pushes → {} reallocations, final capacity {}", reallocs, s.capacity_hint());
+    assert!(reallocs <= 12); // ~log2(1000) with doubling
+    assert!(s.capacity_hint() >= s.len());
+
+    // Pre-sizing avoids them entirely.
+    let mut pre = VecQueue::with_capacity(1000);
+    let cap = pre.capacity_hint();
+    for i in 0..1000 { pre.push(i); }
+    assert_eq!(pre.capacity_hint(), cap);
+    assert_eq!(pre.len(), 1000);
+}
+
+// Maximum of every length-`k` window in O(n) total (naive is O(n·k)).
+// The deque holds *indices* whose values are strictly decreasing front → back:
+// - a new value evicts smaller ones from the back (they can never be a max again),
+// - the front falls out once it slides past the window's left edge,
+// - so the front is always the current window's max. Each index enters/leaves once.
+// Like `slice::windows`: `k > xs.len()` gives no windows, and `k == 0` panics.
+fn sliding_window_max(xs: &[i32], k: usize) -> Vec<i32> {
+    assert!(k > 0, "window size must be non-zero");
+    let mut out = Vec::with_capacity(xs.len().saturating_sub(k - 1));
+    let mut idx: VecDeque<usize> = VecDeque::new();
+    for (i, &x) in xs.iter().enumerate() {
+        while idx.back().is_some_and(|&j| xs[j] <= x) {
+            idx.pop_back();
+        }
+        idx.push_back(i);
+        if idx[0] + k <= i {
+            idx.pop_front(); // left the window [i + 1 - k, i]
+        }
+        if i + 1 >= k {
+            out.push(xs[idx[0]]);
+        }
+    }
+    out
+}
+
+pub fn example_sliding_window_max() {
+    println!("\n== Sliding-window max (monotonic VecDeque) ==");
+    let mixed = [1, 3, -1, -3, 5, 3, 6, 7];
+    let maxes = sliding_window_max(&mixed, 3);
+    println!("max of each 3-window of {:?} = {:?}", mixed, maxes);
+    assert_eq!(maxes, [3, 3, 5, 5, 6, 7]);
+    // Same answer as the obvious O(n·k) version:
+    let naive: Vec<i32> = mixed.windows(3).map(|w| *w.iter().max().unwrap()).collect();
+    assert_eq!(maxes, naive);
+
+    assert_eq!(sliding_window_max(&[1, 2, 3, 4, 5], 2), [2, 3, 4, 5]); // increasing
+    assert_eq!(sliding_window_max(&[5, 4, 3, 2, 1], 2), [5, 4, 3, 2]); // decreasing
+    assert_eq!(sliding_window_max(&[2, 2, 2], 2), [2, 2]);              // ties
+    assert_eq!(sliding_window_max(&mixed, 1), mixed);                    // k = 1: identity
+    assert_eq!(sliding_window_max(&mixed, mixed.len()), [7]);            // k = len: one window
+    assert!(sliding_window_max(&mixed, mixed.len() + 1).is_empty());     // no full window
+    assert!(sliding_window_max(&[], 3).is_empty());
+
+    // k = 0 is rejected with a panic, as `windows(0)` is.
+    // (The panic message printed to stderr is expected.)
+    assert!(std::panic::catch_unwind(|| sliding_window_max(&[1, 2], 0)).is_err());
+}
+
+// Quickselect: the `n`-th smallest element (0-based) in average O(n), without sorting.
+// Partition around a pivot, then keep only the side that contains index `n`.
+// Side effect: `v` is *reordered* — afterwards `v[n]` holds the answer, everything left
+// of it is `<=` and everything right of it is `>=`, but neither side is sorted.
+// (std ships the same thing as `slice::select_nth_unstable`, with worst-case guarantees.)
+// Panics if `n >= v.len()`.
+fn select_nth<T: Ord>(v: &mut [T], n: usize) -> &T {
+    assert!(n < v.len(), "select_nth: index {n} out of range for length {}", v.len());
+    let (mut lo, mut hi) = (0, v.len()); // `n` is always inside v[lo..hi]
+    while hi - lo > 1 {
+        // Middle pivot keeps already-sorted input from hitting the O(n²) case.
+        v.swap(lo + (hi - lo) / 2, hi - 1);
+        // Lomuto partition: v[lo..store] < pivot, pivot lands at `store`.
+        let mut store = lo;
+        for i in lo..hi - 1 {
+            if v[i] < v[hi - 1] {
+                v.swap(i, store);
+                store += 1;
+            }
+        }
+        v.swap(store, hi - 1);
+        match n.cmp(&store) {
+            std::cmp::Ordering::Equal => break,
+            std::cmp::Ordering::Less => hi = store,
+            std::cmp::Ordering::Greater => lo = store + 1,
+        }
+    }
+    &v[n]
+}
+
+pub fn example_select_nth() {
+    println!("\n== Quickselect: nth smallest without a full sort ==");
+    let mut v = vec![9, 1, 8, 2, 7, 3, 6, 4, 5];
+    let median = *select_nth(&mut v, 4);
+    println!("median = {median}, slice afterwards = {:?}", v);
+    assert_eq!(median, 5);
+    // Partitioned around index 4, not sorted.
+    assert!(v[..4].iter().all(|&x| x <= 5) && v[5..].iter().all(|&x| x >= 5));
+
+    // Pseudo-random inputs (tiny LCG, deterministic), every `n` checked against a sorted copy.
+    let mut seed = 0x2545_f491_u64;
+    let mut next = move |m: u64| {
+        seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (seed >> 33) % m
+    };
+    for len in [1, 2, 3, 10, 57] {
+        for range in [1000, 4] { // 4 → duplicate-heavy
+            let data: Vec<u64> = (0..len).map(|_| next(range)).collect();
+            let mut sorted = data.clone();
+            sorted.sort();
+            for n in 0..len {
+                let mut work = data.clone();
+                assert_eq!(*select_nth(&mut work, n), sorted[n], "len {len}, n {n}, {data:?}");
+                assert!(work[..n].iter().all(|x| *x <= work[n]) && work[n + 1..].iter().all(|x| *x >= work[n]));
+            }
+        }
+    }
+
+    // Edges: n = 0 is the min, n = len - 1 the max; all-equal input; agrees with std.
+    let mut v = vec![4, -2, 7, 0];
+    assert_eq!(*select_nth(&mut v, 0), -2);
+    assert_eq!(*select_nth(&mut v, 3), 7);
+    assert_eq!(*select_nth(&mut [3, 3, 3, 3], 2), 3);
+    let mut a = vec![5, 1, 4, 1, 5, 9, 2, 6];
+    let mut b = a.clone();
+    assert_eq!(select_nth(&mut a, 5), b.select_nth_unstable(5).1);
+
+    // Out of range is rejected like an index would be.
+    // (The panic message printed to stderr is expected.)
+    assert!(std::panic::catch_unwind(|| *select_nth(&mut [1, 2], 2)).is_err());
+}
+
+// A `Vec<Vec<T>>` only *represents* a matrix if every row has the same length; nothing in
+// the type enforces it, so `transpose` checks first and reports the first ragged row.
+#[derive(Debug, PartialEq)]
+struct RaggedRow {
+    row: usize,
+    len: usize,
+    expected: usize,
+}
+
+// rows × cols → cols × rows. Column `j` of the input becomes row `j` of the output, so
+// flattening the result (`concat`) reads the input in column-major order.
+// Empty input (0 rows) → empty output; rows of length 0 (n × 0) → also empty (0 × n).
+fn transpose<T: Clone>(rows: &[Vec<T>]) -> Result<Vec<Vec<T>>, RaggedRow> {
+    let cols = rows.first().map_or(0, Vec::len);
+    if let Some((row, r)) = rows.iter().enumerate().find(|(_, r)| r.len() != cols) {
+        return Err(RaggedRow { row, len: r.len(), expected: cols });
+    }
+    Ok((0..cols).map(|j| rows.iter().map(|r| r[j].clone()).collect()).collect())
+}
+
+pub fn example_transpose() {
+    println!("\n== Transpose a Vec<Vec<T>> matrix (with shape check) ==");
+    let m = vec![vec![1, 2, 3], vec![4, 5, 6]]; // 2 × 3
+    let t = transpose(&m).unwrap();
+    println!("{:?} transposed = {:?}", m, t);
+    assert_eq!(t, [vec![1, 4], vec![2, 5], vec![3, 6]]); // 3 × 2
+    assert_eq!(transpose(&t).unwrap(), m);              // involution
+    assert_eq!(t.concat(), [1, 4, 2, 5, 3, 6]);         // flattened = column-major input
+
+    // Vectors: 1 × N ↔ N × 1.
+    let row = vec![vec!['a', 'b', 'c']];
+    let col = transpose(&row).unwrap();
+    assert_eq!(col, [vec!['a'], vec!['b'], vec!['c']]);
+    assert_eq!(transpose(&col).unwrap(), row);
+
+    // Empty shapes.
+    let empty: Vec<Vec<i32>> = vec![];
+    assert!(transpose(&empty).unwrap().is_empty());
+    assert!(transpose(&[Vec::<i32>::new(), Vec::new()]).unwrap().is_empty()); // 2 × 0 → 0 × 2
+
+    // Jagged input is rejected, pointing at the first row that doesn't match row 0.
+    let jagged = vec![vec![1, 2], vec![3, 4], vec![5], vec![6, 7, 8]];
+    assert_eq!(transpose(&jagged), Err(RaggedRow { row: 2, len: 1, expected: 2 }));
+    assert_eq!(transpose(&[vec![1], vec![]]), Err(RaggedRow { row: 1, len: 0, expected: 1 }));
+
+    // Works for non-Copy T (elements are cloned; the input is only borrowed).
+    let words = vec![vec!["x".to_string(), "y".to_string()]];
+    assert_eq!(transpose(&words).unwrap(), [vec!["x".to_string()], vec!["y".to_string()]]);
+}
+
+// Gap buffer: the text editor's Vec. The text lives in one `Vec<char>` with a hole (the
+// gap) at the cursor: `buf[..gap_start]` is the text before the cursor, `buf[gap_end..]`
+// the text after it, and the slots in between are spare capacity.
+// - Typing / backspace at the cursor only moves a gap boundary → O(1).
+// - Moving the cursor by d characters shifts d chars across the gap (`copy_within`) → O(d),
+//   so edits that stay near the cursor are cheap, unlike `String::insert` (always O(n)).
+// - A full gap is grown like a Vec (double), moving the after-cursor tail to the new end.
+pub struct GapBuffer {
+    buf: Vec<char>,
+    gap_start: usize,
+    gap_end: usize,
+}
+
+impl GapBuffer {
+    const FILL: char = '\0'; // gap slots hold a placeholder; never visible in `text()`
+
+    pub fn new() -> Self {
+        GapBuffer { buf: Vec::new(), gap_start: 0, gap_end: 0 }
+    }
+
+    pub fn len(&self) -> usize { self.buf.len() - (self.gap_end - self.gap_start) }
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+    pub fn cursor(&self) -> usize { self.gap_start }
+
+    /// Insert before the cursor; the cursor ends up after the new char.
+    pub fn insert(&mut self, ch: char) {
+        if self.gap_start == self.gap_end {
+            self.grow();
+        }
+        self.buf[self.gap_start] = ch;
+        self.gap_start += 1;
+    }
+
+    /// Backspace: remove and return the char before the cursor.
+    pub fn delete(&mut self) -> Option<char> {
+        if self.gap_start == 0 {
+            return None;
+        }
+        self.gap_start -= 1;
+        Some(std::mem::replace(&mut self.buf[self.gap_start], Self::FILL))
+    }
+
+    /// Put the cursor before the char at index `to` (0..=len). Panics past the end.
+    pub fn move_cursor(&mut self, to: usize) {
+        assert!(to <= self.len(), "cursor {to} out of range for length {}", self.len());
+        if to < self.gap_start {
+            // Left: the chars in to..gap_start move to just before gap_end.
+            let n = self.gap_start - to;
+            self.buf.copy_within(to..self.gap_start, self.gap_end - n);
+            self.gap_start -= n;
+            self.gap_end -= n;
+        } else if to > self.gap_start {
+            // Right: the first n chars after the gap move to gap_start.
+            let n = to - self.gap_start;
+            self.buf.copy_within(self.gap_end..self.gap_end + n, self.gap_start);
+            self.gap_start += n;
+            self.gap_end += n;
+        }
+    }
+
+    pub fn text(&self) -> String {
+        self.buf[..self.gap_start].iter().chain(&self.buf[self.gap_end..]).collect()
+    }
+
+    fn grow(&mut self) {
+        let old_len = self.buf.len();
+        let new_len = (old_len * 2).max(8);
+        let tail = old_len - self.gap_end;
+        self.buf.resize(new_len, Self::FILL);
+        self.buf.copy_within(self.gap_end..old_len, new_len - tail); // tail to the very end
+        self.gap_end = new_len - tail;
+    }
+}
+
+impl Default for GapBuffer {
+    fn default() -> Self { Self::new() }
+}
+
+pub fn example_gap_buffer() {
+    println!("\n== Gap buffer (cursor-local edits in a Vec<char>) ==");
+    // Reference model: a String plus a cursor counted in chars.
+    struct Naive { text: String, cursor: usize }
+    impl Naive {
+        fn byte_at(&self, c: usize) -> usize {
+            self.text.char_indices().nth(c).map_or(self.text.len(), |(i, _)| i)
+        }
+        fn insert(&mut self, ch: char) { let at = self.byte_at(self.cursor); self.text.insert(at, ch); self.cursor += 1; }
+        fn delete(&mut self) -> Option<char> {
+            if self.cursor == 0 { return None; }
+            self.cursor -= 1;
+            Some(self.text.remove(self.byte_at(self.cursor)))
+        }
+    }
+    enum Edit { Type(&'static str), Back(usize), Move(usize) }
+
+    let mut gb = GapBuffer::new();
+    let mut naive = Naive { text: String::new(), cursor: 0 };
+    let script = [
+        Edit::Type("hello world"),
+        Edit::Move(5), Edit::Type(","),        // "hello, world"
+        Edit::Move(12), Edit::Type("!"),       // append
+        Edit::Move(0), Edit::Type("» "),       // non-ASCII at the front
+        Edit::Move(9), Edit::Back(3),          // "» hell|world!"
+        Edit::Type("o — "),                    // "» hello — world!"
+        Edit::Move(0), Edit::Back(1),          // backspace at 0 is a no-op
+        Edit::Move(4), Edit::Type("abcdefghijklmnopqrstuvwxyz0123456789"), // regrows mid-text
+        Edit::Move(40), Edit::Back(40),        // wipe everything before the cursor
+    ];
+    for edit in &script {
+        match *edit {
+            Edit::Type(s) => s.chars().for_each(|c| { gb.insert(c); naive.insert(c); }),
+            Edit::Back(n) => (0..n).for_each(|_| assert_eq!(gb.delete(), naive.delete())),
+            Edit::Move(to) => { gb.move_cursor(to); naive.cursor = to; }
+        }
+        assert_eq!(gb.text(), naive.text);
+        assert_eq!((gb.cursor(), gb.len()), (naive.cursor, naive.text.chars().count()));
+    }
+    println!("text = {:?}, cursor = {}, capacity = {}", gb.text(), gb.cursor(), gb.buf.len());
+
+    // The cursor can't leave the text.
+    // (The panic message printed to stderr is expected.)
+    assert!(std::panic::catch_unwind(|| GapBuffer::new().move_cursor(1)).is_err());
+}
+
+// Append only the items `v` doesn't already contain, in first-seen order — a set-like
+// `extend` that keeps Vec's ordering (unlike collecting into a `HashSet`) and works on
+// unsorted data (unlike `dedup`, which only drops *adjacent* repeats).
+// The `HashSet` is seeded with the existing elements, so each check is O(1) and the whole
+// call is O(len + items) instead of O(len · items) with `contains`. The cost: clones of
+// every kept element, since the set can't borrow from a Vec we're pushing into.
+// Capacity is reserved from the iterator's `size_hint` lower bound before the loop — an
+// upper guess would over-allocate whenever the input is mostly duplicates.
+fn extend_unique<T: Eq + Hash + Clone>(v: &mut Vec<T>, items: impl IntoIterator<Item = T>) {
+    let items = items.into_iter();
+    let mut seen: HashSet<T> = HashSet::with_capacity(v.len() + items.size_hint().0);
+    seen.extend(v.iter().cloned());
+    v.reserve(items.size_hint().0);
+    for item in items {
+        if !seen.contains(&item) {
+            seen.insert(item.clone());
+            v.push(item);
+        }
+    }
+}
+
+pub fn example_extend_unique() {
+    println!("\n== extend_unique: append without duplicates, keep order ==");
+    let mut tags = vec!["rust", "vec"];
+    extend_unique(&mut tags, ["slice", "rust", "heap", "slice", "vec", "alloc"]);
+    println!("tags = {:?}", tags);
+    // Skipped: "rust"/"vec" (already present) and the second "slice" (repeat within input).
+    assert_eq!(tags, ["rust", "vec", "slice", "heap", "alloc"]);
+
+    // Order is first-seen, not sorted; only the new items are appended after the old ones.
+    let mut v = vec![3];
+    extend_unique(&mut v, [9, 1, 9, 3, 5, 1, 7]);
+    assert_eq!(v, [3, 9, 1, 5, 7]);
+
+    // Existing duplicates are left alone — only *incoming* items are filtered.
+    let mut v = vec![1, 1];
+    extend_unique(&mut v, [1, 2, 2]);
+    assert_eq!(v, [1, 1, 2]);
+
+    // Capacity is reserved once up front from the exact-size hint.
+    let mut v: Vec<u32> = Vec::new();
+    extend_unique(&mut v, 0..100);
+    assert!(v.capacity() >= 100);
+    extend_unique(&mut v, (0..100).rev()); // all duplicates: nothing pushed
+    assert_eq!(v.len(), 100);
+
+    // Pseudo-random input: result is duplicate-free and matches a naive O(n²) model.
+    let mut seed = 0x9e37_79b9_u64;
+    let data: Vec<u64> = (0..500)
+        .map(|_| {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (seed >> 33) % 64
+        })
+        .collect();
+    let mut fast = vec![0, 1, 2];
+    extend_unique(&mut fast, data.iter().copied());
+    let mut naive = vec![0, 1, 2];
+    for &x in &data {
+        if !naive.contains(&x) {
+            naive.push(x);
+        }
+    }
+    assert_eq!(fast, naive);
+    assert_eq!(fast.iter().collect::<HashSet<_>>().len(), fast.len());
+}
+
 /*
 Docs-style notes (expanded):
 
@@ -347,6 +897,10 @@ SAFETY/PERF TIPS
 - Prefer `get()`/`get_mut()` when indices may be invalid; indexing panics on OOB.
 - Use iterators (`iter`, `iter_mut`, adapters) for clarity and bounds-checked, fused loops.
 - Sorting/search: `sort`, `sort_by_key`, `binary_search` (requires sorted input).
+- Sorted insert: `binary_search_by` for the index + `insert` (O(n) shift). With duplicates,
+  `binary_search` may return any equal index — use an upper-bound comparator (or `partition_point`).
+- Queues: `Vec::remove(0)` shifts every element (O(n)); use `VecDeque` (`push_back`/`pop_front`, O(1)).
+- Window aggregates: `windows(k)` + fold is O(n·k); a monotonic `VecDeque` of indices gives O(n) max/min.
 - Batch transforms: `retain`, `drain`, `splice`, `split_off` avoid repeated reallocations.
 - Avoid holding references across potential reallocation points (`push`, `reserve`, `append`).
 
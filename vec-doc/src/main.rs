@@ -11,6 +11,14 @@ use vec_doc::{
     example_passing_to_functions,
     example_boxed_slice_return,
     example_safety_and_panic_free,
+    example_run_length_encoding,
+    example_insert_sorted,
+    example_stack_and_queue,
+    example_sliding_window_max,
+    example_select_nth,
+    example_transpose,
+    example_gap_buffer,
+    example_extend_unique,
 };
 
 fn main() {
@@ -26,4 +34,12 @@ fn main() {
     example_passing_to_functions();
     example_boxed_slice_return();
     example_safety_and_panic_free();
+    example_run_length_encoding();
+    example_insert_sorted();
+    example_stack_and_queue();
+    example_sliding_window_max();
+    example_select_nth();
+    example_transpose();
+    example_gap_buffer();
+    example_extend_unique();
 }